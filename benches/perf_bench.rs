@@ -0,0 +1,88 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pluralizer::Pluralizer;
+
+// Run with `--features cache` to measure repeated lookups through the result cache.
+const WORDS: &[&str] = &[
+    "house", "person", "matrix", "sheep", "child", "box", "wife", "cactus", "tomato", "bus",
+];
+
+fn pluralize_10k(c: &mut Criterion) {
+    c.bench_function("pluralize 10k calls", |b| {
+        b.iter(|| {
+            for i in 0..10_000 {
+                black_box(pluralizer::pluralize(
+                    black_box(WORDS[i % WORDS.len()]),
+                    2,
+                    false,
+                ));
+            }
+        })
+    });
+}
+
+fn singularize_10k(c: &mut Criterion) {
+    c.bench_function("singularize 10k calls", |b| {
+        b.iter(|| {
+            for i in 0..10_000 {
+                black_box(pluralizer::pluralize(
+                    black_box(WORDS[i % WORDS.len()]),
+                    1,
+                    false,
+                ));
+            }
+        })
+    });
+}
+
+// Compares deep-cloning the rules for every call, as the old binary did, with borrowing them.
+fn cloned_vs_borrowed_rules(c: &mut Criterion) {
+    let pluralizer = Pluralizer::new();
+    let mut group = c.benchmark_group("rule access 100 calls");
+    group.sample_size(10);
+
+    group.bench_function("cloned", |b| {
+        b.iter(|| {
+            for i in 0..100 {
+                let rules = pluralizer.clone();
+                black_box(rules.plural(black_box(WORDS[i % WORDS.len()])));
+            }
+        })
+    });
+
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            for i in 0..100 {
+                black_box(pluralizer.plural(black_box(WORDS[i % WORDS.len()])));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+// Compares reading the global rules through their lock with reading them frozen. Freezing can't be
+// undone, so this has to run last.
+fn locked_vs_frozen_rules(c: &mut Criterion) {
+    let mut group = c.benchmark_group("global rules 10k calls");
+    let pluralize_10k = || {
+        for i in 0..10_000 {
+            black_box(pluralizer::plural(black_box(WORDS[i % WORDS.len()])));
+        }
+    };
+
+    group.bench_function("locked", |b| b.iter(pluralize_10k));
+
+    pluralizer::freeze();
+    group.bench_function("frozen", |b| b.iter(pluralize_10k));
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    pluralize_10k,
+    singularize_10k,
+    cloned_vs_borrowed_rules,
+    locked_vs_frozen_rules
+);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::constants;
+
+/// Indefinite article placed before a singular noun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Article {
+    /// "a", used before consonant sounds, e.g. "a house" or "a unicorn".
+    A,
+    /// "an", used before vowel sounds, e.g. "an apple" or "an hour".
+    An,
+}
+
+impl Article {
+    /// The article as written in lower case.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Article::A => "a",
+            Article::An => "an",
+        }
+    }
+
+    /// Guess the article from the spelling of the word.
+    pub(crate) fn guess(word: &str) -> Article {
+        let word = word.to_lowercase();
+        let starts_with = |prefixes: &[&str]| prefixes.iter().any(|p| word.starts_with(p));
+
+        if word.starts_with(|c: char| c.is_ascii_digit()) {
+            Article::guess_number(&word)
+        } else if starts_with(constants::AN_ARTICLE_PREFIXES) {
+            Article::An
+        } else if starts_with(constants::A_ARTICLE_PREFIXES) {
+            Article::A
+        } else if word.starts_with(['a', 'e', 'i', 'o', 'u']) {
+            Article::An
+        } else {
+            Article::A
+        }
+    }
+
+    /// Guess the article from how a number is read, e.g. "an 8", "an 11" and "an 18,000".
+    fn guess_number(word: &str) -> Article {
+        let digits = word
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == ',')
+            .filter(char::is_ascii_digit)
+            .collect::<String>();
+
+        // "eleven" and "eighteen" are only read when they lead a group of thousands, so "an 11"
+        // and "an 11,000" but "a 110".
+        let leads_group = digits.len() % 3 == 2;
+
+        if digits.starts_with('8')
+            || (leads_group && (digits.starts_with("11") || digits.starts_with("18")))
+        {
+            Article::An
+        } else {
+            Article::A
+        }
+    }
+}
+
+impl fmt::Display for Article {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
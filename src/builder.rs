@@ -0,0 +1,111 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use regex::Regex;
+
+use crate::{Pluralizer, RuleError, UncountableRule};
+
+/// Builder to configure a [Pluralizer](Pluralizer) in one expression.
+///
+/// Created by [Pluralizer::builder](Pluralizer::builder). The instance starts empty unless
+/// [with_defaults](PluralizerBuilder::with_defaults) is called, and the rules are added in the
+/// order they were given once [build](PluralizerBuilder::build) is called.
+///
+/// # Examples
+#[cfg_attr(feature = "default-rules", doc = "```")]
+#[cfg_attr(not(feature = "default-rules"), doc = "```ignore")]
+/// use pluralizer::Pluralizer;
+/// use regex::Regex;
+///
+/// let pluralizer = Pluralizer::builder()
+///     .with_defaults()
+///     .irregular("cow", "kine")
+///     .uncountable("rice")
+///     .plural_rule(Regex::new("(?i)(quiz)$").unwrap(), "$1zes")
+///     .build()
+///     .unwrap();
+///
+/// pluralizer.plural("cow"); // kine
+/// pluralizer.plural("quiz"); // quizzes
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use = "the builder does nothing until it's built"]
+pub struct PluralizerBuilder {
+    defaults: bool,
+    irregular: Vec<(String, String)>,
+    uncountable: Vec<String>,
+    plural: Vec<(Regex, String)>,
+    singular: Vec<(Regex, String)>,
+}
+
+impl PluralizerBuilder {
+    /// Seed the instance with the default rules instead of starting empty.
+    #[cfg(feature = "default-rules")]
+    pub fn with_defaults(mut self) -> Self {
+        self.defaults = true;
+        self
+    }
+
+    /// Add an irregular word definition.
+    ///
+    /// See [add_irregular_rule](crate::add_irregular_rule).
+    pub fn irregular(mut self, singular: &str, plural: &str) -> Self {
+        self.irregular
+            .push((singular.to_string(), plural.to_string()));
+        self
+    }
+
+    /// Add an uncountable word.
+    ///
+    /// See [add_uncountable_rule](crate::add_uncountable_rule).
+    pub fn uncountable(mut self, word: &str) -> Self {
+        self.uncountable.push(word.to_string());
+        self
+    }
+
+    /// Add a pluralization rule, checked when the instance is built.
+    ///
+    /// See [add_plural_rule](crate::add_plural_rule).
+    pub fn plural_rule(mut self, rule: Regex, placement: &str) -> Self {
+        self.plural.push((rule, placement.to_string()));
+        self
+    }
+
+    /// Add a singularization rule, checked when the instance is built.
+    ///
+    /// See [add_singular_rule](crate::add_singular_rule).
+    pub fn singular_rule(mut self, rule: Regex, placement: &str) -> Self {
+        self.singular.push((rule, placement.to_string()));
+        self
+    }
+
+    /// Create the instance with every rule added.
+    ///
+    /// Fails with the error of the first pluralization or singularization rule that can't be
+    /// added, e.g. a placement referencing a missing capture group.
+    pub fn build(self) -> Result<Pluralizer, RuleError> {
+        let mut pluralizer = if self.defaults {
+            Pluralizer::new()
+        } else {
+            Pluralizer::empty()
+        };
+
+        for (singular, plural) in self.irregular {
+            pluralizer.add_irregular_rule(singular, plural);
+        }
+
+        for word in self.uncountable {
+            pluralizer.add_uncountable_rule(UncountableRule::String(word));
+        }
+
+        for (rule, placement) in self.plural {
+            pluralizer.add_plural_rule(rule, placement)?;
+        }
+
+        for (rule, placement) in self.singular {
+            pluralizer.add_singular_rule(rule, placement)?;
+        }
+
+        Ok(pluralizer)
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::pluralizer::Form;
+
+/// Number of words remembered by default for each pluralizer.
+pub(crate) const DEFAULT_CAPACITY: usize = 1024;
+
+/// Bounded cache of transformed words, evicting the least recently used word once full.
+pub(crate) struct ResultCache(Mutex<Entries>);
+
+struct Entries {
+    capacity: usize,
+    tick: u64,
+    // Indexed by form, so lookups don't need an owned key.
+    words: [HashMap<String, (String, u64)>; 2],
+    // Form and word of every entry by the tick it was last used, oldest first.
+    recency: BTreeMap<u64, (usize, String)>,
+}
+
+impl ResultCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self(Mutex::new(Entries {
+            capacity,
+            tick: 0,
+            words: [HashMap::new(), HashMap::new()],
+            recency: BTreeMap::new(),
+        }))
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, Entries> {
+        match self.0.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.entries().capacity
+    }
+
+    pub(crate) fn get(&self, word: &str, form: Form) -> Option<String> {
+        let mut entries = self.entries();
+        entries.tick += 1;
+
+        let tick = entries.tick;
+        let (result, last_used) = entries.words[form as usize].get_mut(word)?;
+        let result = result.clone();
+        let previous = core::mem::replace(last_used, tick);
+
+        if let Some(entry) = entries.recency.remove(&previous) {
+            entries.recency.insert(tick, entry);
+        }
+
+        Some(result)
+    }
+
+    pub(crate) fn insert(&self, word: &str, form: Form, result: &str) {
+        let mut entries = self.entries();
+
+        if entries.capacity == 0 {
+            return;
+        }
+
+        entries.tick += 1;
+
+        let tick = entries.tick;
+        let previous =
+            entries.words[form as usize].insert(word.to_string(), (result.to_string(), tick));
+
+        match previous {
+            // Another thread cached the word since the lookup, only its recency changes.
+            Some((_, previous)) => {
+                entries.recency.remove(&previous);
+            }
+            None if entries.recency.len() >= entries.capacity => entries.evict(),
+            None => {}
+        }
+
+        entries
+            .recency
+            .insert(tick, (form as usize, word.to_string()));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        let entries = &mut *self.entries();
+
+        for words in entries.words.iter_mut() {
+            words.clear();
+        }
+
+        entries.recency.clear();
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.entries().capacity = capacity;
+        self.clear();
+    }
+}
+
+impl Entries {
+    fn evict(&mut self) {
+        if let Some((_, (form, word))) = self.recency.pop_first() {
+            self.words[form].remove(&word);
+        }
+    }
+}
+
+impl Clone for ResultCache {
+    fn clone(&self) -> Self {
+        Self::new(self.capacity())
+    }
+}
+
+impl fmt::Debug for ResultCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultCache")
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::string::String;
+
+/// Casing applied to a transformed word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CaseMode {
+    /// Follow the casing of the input, e.g. "House" becomes "Houses".
+    #[default]
+    Preserve,
+    /// Lower case the whole word.
+    Lower,
+    /// Upper case the whole word.
+    Upper,
+    /// Upper case the first letter and lower case the rest.
+    Title,
+    /// Keep the casing of every letter of the input, only the letters that change take the case of
+    /// the rules, e.g. "GeeSE" stays "GeeSE" where preserving its casing gives "Geese".
+    Keep,
+}
+
+impl CaseMode {
+    /// Apply the casing to a word transformed from the original one.
+    pub(crate) fn apply_from(self, original: &str, word: String) -> String {
+        match self {
+            CaseMode::Keep => keep_case(original, word),
+            _ => self.apply(word),
+        }
+    }
+
+    pub(crate) fn apply(self, word: String) -> String {
+        match self {
+            // Without the original word there's no casing to keep.
+            CaseMode::Preserve | CaseMode::Keep => word,
+            CaseMode::Lower => word.to_lowercase(),
+            CaseMode::Upper => word.to_uppercase(),
+            CaseMode::Title => {
+                let mut chars = word.chars();
+
+                match chars.next() {
+                    Some(first) => first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect(),
+                    None => word,
+                }
+            }
+        }
+    }
+}
+
+/// Copy the letters of the original word over the start they share with the transformed word,
+/// ignoring case, e.g. "iPHONE" and "iphones" give "iPHONEs".
+fn keep_case(original: &str, word: String) -> String {
+    let shared = original
+        .char_indices()
+        .zip(word.char_indices())
+        .take_while(|((_, a), (_, b))| a.to_lowercase().eq(b.to_lowercase()))
+        .last()
+        .map(|((i, a), (j, b))| (i + a.len_utf8(), j + b.len_utf8()));
+
+    match shared {
+        Some((original_end, word_end)) => {
+            let mut result = String::from(&original[..original_end]);
+            result.push_str(&word[word_end..]);
+
+            result
+        }
+        None => word,
+    }
+}
@@ -0,0 +1,151 @@
+use alloc::format;
+
+/// CLDR plural category of a number, used to select the matching message form.
+///
+/// Languages only use a subset of the categories, English for example only has
+/// [One](PluralCategory::One) and [Other](PluralCategory::Other).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The CLDR operands of a number.
+struct Operands {
+    /// Integer digits of the absolute value.
+    i: u128,
+    /// Number of visible fraction digits.
+    v: usize,
+}
+
+impl Operands {
+    fn new(count: f64) -> Self {
+        // Display never uses an exponent and prints the shortest round-tripping digits, so
+        // `1.0` has no visible fraction digits and `1.5` has one.
+        let digits = format!("{}", count.abs());
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+        Self {
+            i: integer.parse().unwrap_or(u128::MAX),
+            v: fraction.len(),
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        self.v == 0
+    }
+}
+
+type CategoryRule = fn(&Operands) -> PluralCategory;
+
+/// Plural category rules by language, following the CLDR plural rules.
+const CATEGORY_RULES: &[(&str, CategoryRule)] = &[
+    ("ar", arabic),
+    ("de", english),
+    ("en", english),
+    ("es", spanish),
+    ("fr", french),
+    ("pl", polish),
+    ("ru", russian),
+];
+
+/// Find the CLDR plural category of a number in a language.
+///
+/// The language is matched by its primary subtag, so `"en-US"` uses the English rules. Unknown
+/// languages, NaN and infinite counts always return [Other](PluralCategory::Other).
+///
+/// # Examples
+/// ```
+/// use pluralizer::{plural_category, PluralCategory};
+///
+/// plural_category(1.0, "en"); // PluralCategory::One
+/// plural_category(3.0, "pl"); // PluralCategory::Few
+/// plural_category(5.0, "ru"); // PluralCategory::Many
+/// plural_category(2.0, "ar"); // PluralCategory::Two
+/// ```
+pub fn plural_category(count: f64, lang: &str) -> PluralCategory {
+    let primary = lang.split(['-', '_']).next().unwrap_or_default();
+    let rule = CATEGORY_RULES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(primary));
+
+    match rule {
+        Some((_, rule)) if count.is_finite() => rule(&Operands::new(count)),
+        _ => PluralCategory::Other,
+    }
+}
+
+fn english(n: &Operands) -> PluralCategory {
+    if n.i == 1 && n.is_integer() {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+fn spanish(n: &Operands) -> PluralCategory {
+    if n.i == 1 && n.is_integer() {
+        PluralCategory::One
+    } else if is_million_multiple(n) {
+        PluralCategory::Many
+    } else {
+        PluralCategory::Other
+    }
+}
+
+fn french(n: &Operands) -> PluralCategory {
+    if n.i <= 1 {
+        PluralCategory::One
+    } else if is_million_multiple(n) {
+        PluralCategory::Many
+    } else {
+        PluralCategory::Other
+    }
+}
+
+fn polish(n: &Operands) -> PluralCategory {
+    if !n.is_integer() {
+        return PluralCategory::Other;
+    }
+
+    match (n.i, n.i % 10, n.i % 100) {
+        (1, _, _) => PluralCategory::One,
+        (_, 2..=4, m) if !(12..=14).contains(&m) => PluralCategory::Few,
+        _ => PluralCategory::Many,
+    }
+}
+
+fn russian(n: &Operands) -> PluralCategory {
+    if !n.is_integer() {
+        return PluralCategory::Other;
+    }
+
+    match (n.i % 10, n.i % 100) {
+        (1, m) if m != 11 => PluralCategory::One,
+        (2..=4, m) if !(12..=14).contains(&m) => PluralCategory::Few,
+        _ => PluralCategory::Many,
+    }
+}
+
+fn arabic(n: &Operands) -> PluralCategory {
+    if !n.is_integer() {
+        return PluralCategory::Other;
+    }
+
+    match (n.i, n.i % 100) {
+        (0, _) => PluralCategory::Zero,
+        (1, _) => PluralCategory::One,
+        (2, _) => PluralCategory::Two,
+        (_, 3..=10) => PluralCategory::Few,
+        (_, 11..=99) => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+fn is_million_multiple(n: &Operands) -> bool {
+    n.is_integer() && n.i != 0 && n.i.is_multiple_of(1_000_000)
+}
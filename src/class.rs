@@ -0,0 +1,13 @@
+/// How a word is handled by the rules, returned by [classify_word](crate::classify_word).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordClass {
+    /// The word has no plural and is never changed, e.g. "rice".
+    Uncountable,
+    /// The word has an irregular singular or plural form, e.g. "goose" and "geese".
+    Irregular,
+    /// The word is converted through the regular expression rules, e.g. "house", or only takes a
+    /// plain `s` where the rules would add more, e.g. "photo" and "roof".
+    Regular,
+    /// The singular and plural forms are identical but the word is still counted, e.g. "species".
+    Invariant,
+}
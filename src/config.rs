@@ -0,0 +1,54 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Rules that can be exported and loaded as a whole, e.g. from a vocabulary file.
+///
+/// Regular expressions are stored as their pattern strings. With the `serde` feature the
+/// configuration can be serialized, every field being optional when deserializing.
+///
+/// # Examples
+/// ```
+/// use pluralizer::{RegexRule, RuleConfig};
+///
+/// let config = RuleConfig {
+///     irregular: vec![("cow".to_string(), "kine".to_string())],
+///     plural: vec![RegexRule {
+///         pattern: "(?i)(ox)$".to_string(),
+///         placement: "$1en".to_string(),
+///     }],
+///     uncountable: vec!["cash".to_string()],
+///     ..RuleConfig::default()
+/// };
+///
+/// pluralizer::load_config(&config).unwrap();
+///
+/// let result = pluralizer::pluralize("cow", 2, false); // kine
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct RuleConfig {
+    /// Irregular `(singular, plural)` pairs.
+    pub irregular: Vec<(String, String)>,
+    /// Pluralization rules, in the order they're added.
+    pub plural: Vec<RegexRule>,
+    /// Singularization rules, in the order they're added.
+    pub singular: Vec<RegexRule>,
+    /// Uncountable words.
+    pub uncountable: Vec<String>,
+    /// Uncountable words matched with their exact casing.
+    pub uncountable_exact: Vec<String>,
+    /// Uncountable regular expressions, added after the other rules so they take precedence.
+    pub uncountable_regex: Vec<String>,
+}
+
+/// A regular expression rule and the placement replacing its matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegexRule {
+    pub pattern: String,
+    pub placement: String,
+}
@@ -1,5 +1,5 @@
 /// Irregular rules
-pub(crate) const IRREGULAR_RULES: &'static [(&str, &str)] = &[
+pub(crate) const IRREGULAR_RULES: &[(&str, &str)] = &[
     // Pronouns.
     ("I", "we"),
     ("me", "us"),
@@ -39,6 +39,7 @@ pub(crate) const IRREGULAR_RULES: &'static [(&str, &str)] = &[
     ("anathema", "anathemata"),
     // Other irregular rules.
     ("ox", "oxen"),
+    ("person", "people"),
     ("axe", "axes"),
     ("die", "dice"),
     ("yes", "yeses"),
@@ -47,6 +48,7 @@ pub(crate) const IRREGULAR_RULES: &'static [(&str, &str)] = &[
     ("goose", "geese"),
     ("tooth", "teeth"),
     ("quiz", "quizzes"),
+    ("fez", "fezzes"),
     ("human", "humans"),
     ("proof", "proofs"),
     ("carve", "carves"),
@@ -60,7 +62,7 @@ pub(crate) const IRREGULAR_RULES: &'static [(&str, &str)] = &[
 ];
 
 /// Pluralization rules
-pub(crate) const PLURAL_RULES: &'static [(&str, &str)] = &[
+pub(crate) const PLURAL_RULES: &[(&str, &str)] = &[
     ("(?i)s?$", "s"),
     (r"(?i)[^\u0000-\u007F]$", "$0"),
     (r"(?i)([^aeiou]ese)$", "$1"),
@@ -75,19 +77,20 @@ pub(crate) const PLURAL_RULES: &'static [(&str, &str)] = &[
     (r"(?i)(alumn|alg|vertebr)(?:a|ae)$", "$1ae"),
     (r"(?i)(seraph|cherub)(?:im)?$", "$1im"),
     (r"(?i)(her|at|gr)o$", "$1oes"),
+    // Latin neuters that always take `-a`, other words ending in `-um` take `s`, e.g. "album".
     (
-        r"(?i)(agend|addend|millenni|dat|extrem|bacteri|desiderat|strat|candelabr|errat|ov|symposi|curricul|automat|quor)(?:a|um)$",
+        r"(?i)(agend|addend|millenni|dat|extrem|bacteri|desiderat|strat|candelabr|errat|ov|symposi|curricul|automat|quor|quant|spectr|corrigend)(?:a|um)$",
         "$1a",
     ),
     (
-        r"(?i)(apheli|hyperbat|periheli|asyndet|noumen|phenomen|criteri|organ|prolegomen|hedr|automat)(?:a|on)$",
+        r"(?i)(apheli|hyperbat|periheli|asyndet|noumen|phenomen|criteri|organ|prolegomen|hedr|automat|gangli)(?:a|on)$",
         "$1a",
     ),
     (r"(?i)sis$", "ses"),
     (r"(?i)(?:(kni|wi|li)fe|(ar|l|ea|eo|oa|hoo)f)$", "$1$2ves"),
     (r"(?i)([^aeiouy]|qu)y$", "$1ies"),
     (r"(?i)([^ch][ieo][ln])ey$", "$1ies"),
-    (r"(?i)(x|ch|ss|sh|zz)$", "$1es"),
+    (r"(?i)(x|ch|ss|sh|z)$", "$1es"),
     (
         r"(?i)(matr|cod|mur|sil|vert|ind|append)(?:ix|ex)$",
         "$1ices",
@@ -101,7 +104,7 @@ pub(crate) const PLURAL_RULES: &'static [(&str, &str)] = &[
 ];
 
 /// Singularization rules
-pub(crate) const SINGULAR_RULES: &'static [(&str, &str)] = &[
+pub(crate) const SINGULAR_RULES: &[(&str, &str)] = &[
     ("(?i)s$", ""),
     ("(?i)(ss)$", "$1"),
     ("(?i)(wi|kni|(?:after|half|high|low|mid|non|night|[^\\w]|^)li)ves$", "$1fe"),
@@ -112,13 +115,19 @@ pub(crate) const SINGULAR_RULES: &'static [(&str, &str)] = &[
     ("(?i)\\b(mon|smil)ies$", "$1ey"),
     ("(?i)\\b((?:tit)?m|l)ice$", "$1ouse"),
     ("(?i)(seraph|cherub)im$", "$1"),
-    ("(?i)(x|ch|ss|sh|zz|tto|go|cho|alias|[^aou]us|t[lm]as|gas|(?:her|at|gr)o|[aeiou]ris)(?:es)?$", "$1"),
-    ("(?i)(analy|diagno|parenthe|progno|synop|the|empha|cri|ne)(?:sis|ses)$", "$1sis"),
+    ("(?i)(x|ch|ss|sh|zz|[^z]z|tto|go|cho|alias|[^aou]us|t[lm]as|gas|(?:her|at|gr)o|[aeiou]ris)(?:es)?$", "$1"),
+    // Words ending in `-ze` also take `-s` after a single `z`, e.g. "sizes" but "topazes".
+    ("(?i)([^p]a|[eiouy]|[dn])zes$", "$1ze"),
+    ("(?i)(analy|diagno|parenthe|progno|synop|the|empha|cri|ne|oa|metasta)(?:sis|ses)$", "$1sis"),
+    // Medical terms ending in "-osis", e.g. "neurosis".
+    ("(?i)((?:neur|psych|thromb|fibr|hypn|mit|scoli|symbi)o)(?:sis|ses)$", "$1sis"),
     ("(?i)(movie|twelve|abuse|e[mn]u)s$", "$1"),
     ("(?i)(test)(?:is|es)$", "$1is"),
     ("(?i)(alumn|syllab|vir|radi|nucle|fung|cact|stimul|termin|bacill|foc|uter|loc|strat)(?:us|i)$", "$1us"),
-    ("(?i)(agend|addend|millenni|dat|extrem|bacteri|desiderat|strat|candelabr|errat|ov|symposi|curricul|quor)a$", "$1um"),
-    ("(?i)(apheli|hyperbat|periheli|asyndet|noumen|phenomen|criteri|organ|prolegomen|hedr|automat)a$", "$1on"),
+    ("(?i)(agend|addend|millenni|dat|extrem|bacteri|desiderat|strat|candelabr|errat|ov|symposi|curricul|quor|quant|spectr|corrigend)a$", "$1um"),
+    // Classical plurals of words usually pluralized with `s`, e.g. "memoranda" and "memorandums".
+    ("(?i)(memorand|referend|stadi|aquari|empori|moratori|consorti|crani|maxim|minim|optim|\\bfor)a$", "$1um"),
+    ("(?i)(apheli|hyperbat|periheli|asyndet|noumen|phenomen|criteri|organ|prolegomen|hedr|automat|gangli)a$", "$1on"),
     ("(?i)(alumn|alg|vertebr)ae$", "$1a"),
     ("(?i)(cod|mur|sil|vert|ind)ices$", "$1ex"),
     ("(?i)(matr|append)ices$", "$1ix"),
@@ -128,7 +137,41 @@ pub(crate) const SINGULAR_RULES: &'static [(&str, &str)] = &[
     ("(?i)men$", "man"),
 ];
 
-pub(crate) const UNCOUNTABLE_RULES: &'static [&str] = &[
+/// Classical Latin and Greek plurals, preferred over the anglicized forms in classical mode.
+pub(crate) const CLASSICAL_IRREGULAR_RULES: &[(&str, &str)] = &[
+    ("antenna", "antennae"),
+    ("apex", "apices"),
+    ("aquarium", "aquaria"),
+    ("bureau", "bureaux"),
+    ("consortium", "consortia"),
+    ("corpus", "corpora"),
+    ("cranium", "crania"),
+    ("emporium", "emporia"),
+    ("formula", "formulae"),
+    ("forum", "fora"),
+    ("genius", "genii"),
+    ("hippopotamus", "hippopotami"),
+    ("larva", "larvae"),
+    ("maximum", "maxima"),
+    ("medium", "media"),
+    ("memorandum", "memoranda"),
+    ("minimum", "minima"),
+    ("moratorium", "moratoria"),
+    ("nebula", "nebulae"),
+    ("octopus", "octopi"),
+    ("optimum", "optima"),
+    ("opus", "opera"),
+    ("plateau", "plateaux"),
+    ("referendum", "referenda"),
+    ("soprano", "soprani"),
+    ("stadium", "stadia"),
+    ("thesaurus", "thesauri"),
+    ("ultimatum", "ultimata"),
+    ("virtuoso", "virtuosi"),
+    ("vortex", "vortices"),
+];
+
+pub(crate) const UNCOUNTABLE_RULES: &[&str] = &[
     // Singular words with no plurals.
     "adulthood",
     "advice",
@@ -225,7 +268,7 @@ pub(crate) const UNCOUNTABLE_RULES: &'static [&str] = &[
     "you",
 ];
 
-pub(crate) const UNCOUNTABLE_REGEX_RULES: &'static [&str] = &[
+pub(crate) const UNCOUNTABLE_REGEX_RULES: &[&str] = &[
     "(?i)pok[eé]mon$",
     "(?i)[^aeiou]ese$", // "chinese", "japanese"
     "(?i)deer$",        // "deer", "reindeer"
@@ -235,3 +278,166 @@ pub(crate) const UNCOUNTABLE_REGEX_RULES: &'static [&str] = &[
     "(?i)pox$",    // "chickpox", "smallpox"
     "(?i)sheep$",
 ];
+
+/// Words ending in `o` that only take an `s`, e.g. "photos" and "pianos" but "potatoes".
+///
+/// Only words that would otherwise match a rule adding `es` need to be listed, but common words are
+/// listed anyway to keep them safe from rules added later.
+pub(crate) const O_EXCEPTIONS: &[&str] = &[
+    "auto",
+    "avocado",
+    "casino",
+    "combo",
+    "disco",
+    "euro",
+    "gelato",
+    "halo",
+    "kilo",
+    "kimono",
+    "legato",
+    "logo",
+    "macro",
+    "memo",
+    "metro",
+    "moderato",
+    "ostinato",
+    "photo",
+    "piano",
+    "pizzicato",
+    "pro",
+    "radio",
+    "rubato",
+    "silo",
+    "staccato",
+    "studio",
+    "tattoo",
+    "typo",
+    "vibrato",
+    "video",
+    "zoo",
+];
+
+/// Words ending in `f` or `fe` that only take an `s`, e.g. "roofs" and "beliefs" but "leaves".
+///
+/// Only words that would otherwise match the rule changing the ending to `ves` need to be listed,
+/// but common words are listed anyway to keep them safe from rules added later.
+pub(crate) const F_EXCEPTIONS: &[&str] = &[
+    "belief", "chef", "chief", "cliff", "golf", "gulf", "oaf", "reef", "roof", "safe", "spoof",
+];
+
+/// Contractions that look like possessives or plurals but are never changed, e.g. "it's".
+pub(crate) const CONTRACTIONS: &[&str] = &[
+    "ain't",
+    "aren't",
+    "can't",
+    "couldn't",
+    "didn't",
+    "doesn't",
+    "don't",
+    "hadn't",
+    "hasn't",
+    "haven't",
+    "he'd",
+    "he'll",
+    "he's",
+    "here's",
+    "i'd",
+    "i'll",
+    "i'm",
+    "i've",
+    "isn't",
+    "it'll",
+    "it's",
+    "let's",
+    "mustn't",
+    "she'd",
+    "she'll",
+    "she's",
+    "shouldn't",
+    "that's",
+    "there's",
+    "they'd",
+    "they'll",
+    "they're",
+    "they've",
+    "wasn't",
+    "we'd",
+    "we'll",
+    "we're",
+    "we've",
+    "weren't",
+    "what's",
+    "where's",
+    "who's",
+    "won't",
+    "wouldn't",
+    "you'd",
+    "you'll",
+    "you're",
+    "you've",
+];
+
+/// Collective nouns for groups of animals, e.g. "a pride of lions".
+pub(crate) const COLLECTIVES: &[(&str, &str)] = &[
+    ("ant", "colony"),
+    ("bee", "swarm"),
+    ("bird", "flock"),
+    ("cow", "herd"),
+    ("crow", "murder"),
+    ("dolphin", "pod"),
+    ("elephant", "herd"),
+    ("fish", "school"),
+    ("goose", "gaggle"),
+    ("lion", "pride"),
+    ("owl", "parliament"),
+    ("sheep", "flock"),
+    ("whale", "pod"),
+    ("wolf", "pack"),
+];
+
+/// Unit symbols, which are written the same for any quantity, e.g. "5 kg".
+#[rustfmt::skip]
+pub(crate) const UNIT_SYMBOLS: &[&str] = &[
+    // Length.
+    "nm", "µm", "mm", "cm", "m", "km", "in", "ft", "yd", "mi",
+    // Mass.
+    "µg", "mg", "g", "kg", "t", "oz", "lb",
+    // Time.
+    "ns", "µs", "ms", "s", "min", "h",
+    // Volume.
+    "mL", "ml", "cL", "cl", "dL", "dl", "L", "l",
+    // Temperature.
+    "K", "°C", "°F",
+    // Speed and frequency.
+    "km/h", "m/s", "mph", "rpm", "Hz", "kHz", "MHz", "GHz",
+    // Energy and power.
+    "J", "kJ", "cal", "kcal", "W", "kW", "MW", "kWh",
+    // Electricity and other SI units.
+    "A", "mA", "V", "kV", "Ω", "mol", "cd", "Pa", "kPa", "hPa", "N",
+    // Data and percentages.
+    "B", "kB", "KB", "MB", "GB", "TB", "KiB", "MiB", "GiB", "TiB", "kb", "Mb", "Gb", "%",
+];
+
+/// Prepositions that follow the head noun of a phrase, e.g. "box of chocolates".
+pub(crate) const PREPOSITIONS: &[&str] = &[
+    "about", "above", "across", "after", "against", "along", "among", "around", "at", "before",
+    "behind", "below", "beneath", "beside", "between", "beyond", "by", "during", "for", "from",
+    "in", "inside", "into", "near", "of", "off", "on", "onto", "out", "outside", "over", "per",
+    "through", "to", "toward", "towards", "under", "until", "up", "upon", "with", "within",
+    "without",
+];
+
+/// Words placed after the head noun of a phrase, e.g. "attorney general".
+pub(crate) const POSTPOSITIVES: &[&str] = &["by", "elect", "general", "martial"];
+
+/// Word beginnings spelled with a consonant but pronounced with a vowel sound, e.g. "an hour".
+///
+/// Checked before [A_ARTICLE_PREFIXES] so "onerous" isn't caught by "one".
+pub(crate) const AN_ARTICLE_PREFIXES: &[&str] =
+    &["heir", "honest", "honor", "honour", "hour", "onerous"];
+
+/// Word beginnings spelled with a vowel but pronounced with a consonant sound, e.g. "a unicorn".
+pub(crate) const A_ARTICLE_PREFIXES: &[&str] = &[
+    "eu", "ewe", "once", "one", "ouija", "ubiq", "unicorn", "unif", "union", "uniq", "unis",
+    "unit", "univ", "uran", "ure", "uri", "usa", "use", "usu", "ute", "uti", "uto",
+];
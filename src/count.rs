@@ -0,0 +1,39 @@
+use core::fmt::Display;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Integer types accepted as a count by [pluralize](fn@crate::pluralize).
+///
+/// Implemented for every primitive integer type, so counts don't need to be cast to `isize`. The
+/// trait is sealed and can't be implemented outside of this crate.
+pub trait Count: Copy + Display + private::Sealed {
+    /// The count as an `isize`, saturating counts out of its range.
+    ///
+    /// Saturated counts are only used to pick the singular or plural form, the count itself is
+    /// still printed as is.
+    fn saturating_isize(self) -> isize;
+}
+
+macro_rules! impl_count {
+    ($($t: ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl Count for $t {
+                fn saturating_isize(self) -> isize {
+                    match isize::try_from(self) {
+                        Ok(count) => count,
+                        // Only counts below zero can be under `isize::MIN`.
+                        #[allow(unused_comparisons)]
+                        Err(_) if self < 0 => isize::MIN,
+                        Err(_) => isize::MAX,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_count!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
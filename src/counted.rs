@@ -0,0 +1,39 @@
+use core::fmt;
+
+/// A word pluralized or singularized for its count once it's formatted.
+///
+/// Formatting gives the same result as [pluralize](fn@crate::pluralize) with the current rules,
+/// without building a string beforehand. Width and alignment flags are applied to the whole result.
+///
+/// # Examples
+/// ```
+/// use pluralizer::Counted;
+///
+/// let messages = 3;
+///
+/// format!("You have {}", Counted::new("message", messages, true)); // You have 3 messages
+/// format!("[{:>8}]", Counted::new("message", 1, false)); // [ message]
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Counted<'a> {
+    word: &'a str,
+    count: isize,
+    include_count: bool,
+}
+
+impl<'a> Counted<'a> {
+    /// Wrap a word with its count, see [pluralize](fn@crate::pluralize).
+    pub fn new(word: &'a str, count: isize, include_count: bool) -> Self {
+        Self {
+            word,
+            count,
+            include_count,
+        }
+    }
+}
+
+impl fmt::Display for Counted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&crate::pluralize(self.word, self.count, self.include_count))
+    }
+}
@@ -0,0 +1,62 @@
+use core::error::Error;
+use core::fmt;
+
+/// Error returned when a rule can't be added to the collection.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleError {
+    /// The placement references a capture group the regular expression doesn't have.
+    MissingCaptureGroup {
+        /// Index of the referenced group, e.g. `3` for `$3`.
+        group: usize,
+        /// Number of capture groups in the regular expression, not counting the whole match.
+        groups: usize,
+    },
+    /// The rule would exceed the maximum number of rules set with
+    /// [set_max_rules](crate::set_max_rules).
+    TooManyRules {
+        /// The maximum number of plural and singular rules.
+        max: usize,
+    },
+    /// The rule isn't a valid regular expression.
+    InvalidRegex(regex::Error),
+    /// The global rules were [frozen](crate::freeze) and can't change anymore.
+    Frozen,
+    /// The rule isn't anchored at the end of the word with `$`, returned by
+    /// [add_plural_rule_checked](crate::add_plural_rule_checked) and
+    /// [add_singular_rule_checked](crate::add_singular_rule_checked).
+    Unanchored,
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::MissingCaptureGroup { group, groups } => write!(
+                f,
+                "placement references capture group ${} but the rule only has {} groups",
+                group, groups
+            ),
+            RuleError::TooManyRules { max } => {
+                write!(f, "adding the rule would exceed the limit of {} rules", max)
+            }
+            RuleError::InvalidRegex(error) => write!(f, "invalid rule: {}", error),
+            RuleError::Frozen => write!(f, "the rules are frozen"),
+            RuleError::Unanchored => write!(f, "rule isn't anchored at the end of the word"),
+        }
+    }
+}
+
+impl Error for RuleError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RuleError::InvalidRegex(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<regex::Error> for RuleError {
+    fn from(error: regex::Error) -> Self {
+        RuleError::InvalidRegex(error)
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::string::{String, ToString};
+
+use crate::rules::WordRule;
+
+/// Result of converting a word along with the rule that decided it.
+///
+/// Returned by [explain_plural](crate::explain_plural) and
+/// [explain_singular](crate::explain_singular).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transformation {
+    /// The converted word, as returned by [plural](crate::plural) or [singular](crate::singular).
+    pub result: String,
+    /// What decided the result.
+    pub source: TransformSource,
+}
+
+/// What decided the result of a [Transformation].
+///
+/// For possessives, hyphenated compounds and words surrounded by punctuation, this is the
+/// decision taken for the noun itself, e.g. "mother" in "(mother-in-law's)".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransformSource {
+    /// The word is already in the wanted form according to an irregular rule, e.g. plural "geese".
+    Kept,
+    /// The word was replaced through an irregular rule, e.g. "goose" becomes "geese", or is a word
+    /// ending in `o` that only takes an `s`, e.g. "photo".
+    Irregular,
+    /// The word was replaced through a classical plural in classical mode.
+    Classical,
+    /// A plural acronym dropped its suffix, e.g. "CPUs" becomes "CPU".
+    Acronym,
+    /// A regular expression rule matched the word.
+    Rule {
+        /// Pattern of the rule.
+        pattern: String,
+        /// Placement replacing the match.
+        placement: String,
+    },
+    /// The word matched an uncountable word or regular expression.
+    Uncountable,
+    /// No rule matched, so the word was kept as is.
+    Unchanged,
+}
+
+/// A word that isn't recovered by singularizing its plural, returned by
+/// [verify_roundtrip](crate::verify_roundtrip).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundtripFailure {
+    /// The original word.
+    pub word: String,
+    /// The plural of the word.
+    pub plural: String,
+    /// The singular of the plural, which differs from the word.
+    pub singular: String,
+}
+
+/// Borrowed counterpart of [TransformSource], tracked without allocating while replacing words.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Decision<'r> {
+    Kept,
+    Irregular,
+    Classical,
+    Acronym,
+    Rule(&'r WordRule),
+    Uncountable,
+    Unchanged,
+}
+
+impl From<Decision<'_>> for TransformSource {
+    fn from(decision: Decision<'_>) -> Self {
+        match decision {
+            Decision::Kept => TransformSource::Kept,
+            Decision::Irregular => TransformSource::Irregular,
+            Decision::Classical => TransformSource::Classical,
+            Decision::Acronym => TransformSource::Acronym,
+            Decision::Rule(rule) => TransformSource::Rule {
+                pattern: rule.rule.as_str().to_string(),
+                placement: rule.placement.clone(),
+            },
+            Decision::Uncountable => TransformSource::Uncountable,
+            Decision::Unchanged => TransformSource::Unchanged,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::locales;
+use crate::with_pluralizer;
+use crate::Count;
+use crate::Pluralizer;
+
+#[cfg(feature = "std")]
+type Lazy<T> = std::sync::LazyLock<T>;
+#[cfg(not(feature = "std"))]
+type Lazy<T> = spin::Lazy<T>;
+
+static SPANISH: Lazy<Pluralizer> = Lazy::new(locales::es::pluralizer);
+static GERMAN: Lazy<Pluralizer> = Lazy::new(locales::de::pluralizer);
+
+/// Grammatical gender of a noun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    /// E.g. "el papel" or "der Tisch".
+    Masculine,
+    /// E.g. "la casa" or "die Frau".
+    Feminine,
+    /// E.g. "das Haus". Languages without a neuter gender, like Spanish, treat it as masculine.
+    Neuter,
+}
+
+/// A noun along with its grammatical gender, e.g. "casa" which is feminine in Spanish.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GenderedNoun {
+    /// The noun, in its singular or plural form.
+    pub word: String,
+    /// The gender of the noun.
+    pub gender: Gender,
+}
+
+impl GenderedNoun {
+    /// Create a noun of the given gender.
+    pub fn new(word: impl Into<String>, gender: Gender) -> Self {
+        Self {
+            word: word.into(),
+            gender,
+        }
+    }
+}
+
+/// Pluralize or singularize a noun based on the count, prefixed with the definite article agreeing
+/// with its gender and number.
+///
+/// English ignores the gender and uses the crate level rules, Spanish and German use the rules of
+/// their [locale](crate::locales). The language is a tag like `es` or `es-MX`, only its primary
+/// subtag is used. `None` is returned for other languages. Every language decides which counts
+/// are singular with the [predicate](crate::set_singular_predicate) of the crate level rules.
+///
+/// # Examples
+/// ```
+/// use pluralizer::{Gender, GenderedNoun};
+///
+/// let casa = GenderedNoun::new("casa", Gender::Feminine);
+/// let papel = GenderedNoun::new("papel", Gender::Masculine);
+/// let haus = GenderedNoun::new("Haus", Gender::Neuter);
+///
+/// pluralizer::pluralize_gendered(&casa, 1, "es"); // Some("la casa")
+/// pluralizer::pluralize_gendered(&casa, 2, "es"); // Some("las casas")
+/// pluralizer::pluralize_gendered(&papel, 2, "es"); // Some("los papeles")
+/// pluralizer::pluralize_gendered(&haus, 1, "de"); // Some("das Haus")
+/// pluralizer::pluralize_gendered(&casa, 2, "en"); // Some("the casas")
+/// ```
+pub fn pluralize_gendered(noun: &GenderedNoun, count: impl Count, lang: &str) -> Option<String> {
+    let primary = lang.split(['-', '_']).next().unwrap_or_default();
+    let plural = !with_pluralizer(|p| p.takes_singular(count.saturating_isize()));
+
+    let (article, word) = match primary.to_ascii_lowercase().as_str() {
+        "en" => ("the", crate::pluralize_if(&noun.word, plural)),
+        "es" => (
+            spanish_article(noun.gender, plural),
+            SPANISH.pluralize_if(&noun.word, plural),
+        ),
+        "de" => (
+            german_article(noun.gender, plural),
+            GERMAN.pluralize_if(&noun.word, plural),
+        ),
+        _ => return None,
+    };
+
+    Some(format!("{} {}", article, word))
+}
+
+fn spanish_article(gender: Gender, plural: bool) -> &'static str {
+    match (gender, plural) {
+        (Gender::Feminine, false) => "la",
+        (Gender::Feminine, true) => "las",
+        (Gender::Masculine | Gender::Neuter, false) => "el",
+        (Gender::Masculine | Gender::Neuter, true) => "los",
+    }
+}
+
+fn german_article(gender: Gender, plural: bool) -> &'static str {
+    match (gender, plural) {
+        // Every gender shares the same plural article.
+        (_, true) => "die",
+        (Gender::Masculine, false) => "der",
+        (Gender::Feminine, false) => "die",
+        (Gender::Neuter, false) => "das",
+    }
+}
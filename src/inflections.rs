@@ -0,0 +1,145 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::CaseMode;
+
+/// Both forms of a word, computed once to pick one by count without going through the rules again.
+///
+/// Returned by [inflect](crate::inflect).
+#[derive(Debug, Clone)]
+pub struct Inflection {
+    /// The singular form of the word.
+    pub singular: String,
+    /// The plural form of the word.
+    pub plural: String,
+    takes_singular: fn(isize) -> bool,
+}
+
+impl Inflection {
+    pub(crate) fn new(singular: String, plural: String, takes_singular: fn(isize) -> bool) -> Self {
+        Self {
+            singular,
+            plural,
+            takes_singular,
+        }
+    }
+
+    /// Pick the form for the count, following the [singular predicate](crate::set_singular_predicate)
+    /// set when the word was inflected.
+    pub fn for_count(&self, count: isize) -> &str {
+        if (self.takes_singular)(count) {
+            &self.singular
+        } else {
+            &self.plural
+        }
+    }
+}
+
+/// Convert a CamelCase name to snake_case.
+///
+/// Runs of capitals are kept together as one word, except for the last capital starting the next
+/// word. Hyphens and spaces are also turned into underscores.
+///
+/// # Examples
+/// ```
+/// pluralizer::underscore("BlogPost"); // blog_post
+/// pluralizer::underscore("HTTPServer"); // http_server
+/// pluralizer::underscore("getHTTPResponseCode"); // get_http_response_code
+/// ```
+pub fn underscore(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<char>>();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+
+            // Split "BlogPost" before "Post" and "HTTPRequest" before "Request".
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                result.push('_');
+            }
+        }
+
+        match c {
+            '-' | ' ' => result.push('_'),
+            _ => result.extend(c.to_lowercase()),
+        }
+    }
+
+    result
+}
+
+/// Convert a snake_case name to CamelCase.
+///
+/// The first letter of every word is upper cased and the rest is kept as is, so acronyms come out
+/// as regular words, e.g. "http_server" becomes "HttpServer".
+///
+/// # Examples
+/// ```
+/// pluralizer::camelize("blog_post"); // BlogPost
+/// pluralizer::camelize("BlogPost"); // BlogPost
+/// ```
+pub fn camelize(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Replace the underscores of a snake_case name with dashes.
+///
+/// # Examples
+/// ```
+/// pluralizer::dasherize("blog_post"); // blog-post
+/// ```
+pub fn dasherize(name: &str) -> String {
+    name.replace('_', "-")
+}
+
+/// Turn an identifier into a readable label.
+///
+/// A trailing `_id` is removed, runs of underscores and whitespace become a single space, and the
+/// first letter is upper cased while the rest is lower cased.
+///
+/// # Examples
+/// ```
+/// pluralizer::humanize("first_name"); // First name
+/// pluralizer::humanize("author_id"); // Author
+/// pluralizer::humanize("  shipping__ADDRESS "); // Shipping address
+/// ```
+pub fn humanize(name: &str) -> String {
+    let name = name.trim();
+    let name = match name.strip_suffix("_id") {
+        Some(stripped) if !stripped.is_empty() => stripped,
+        _ => name,
+    };
+
+    let words = name
+        .split(|c: char| c == '_' || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>();
+
+    CaseMode::Title.apply(words.join(" "))
+}
+
+/// Apply a conversion to the last word of a snake_case name, e.g. "post" in "blog_post".
+pub(crate) fn map_last_word(name: &str, convert: impl FnOnce(&str) -> String) -> String {
+    match name.rsplit_once('_') {
+        Some((head, last)) => {
+            let mut result = String::from(head);
+            result.push('_');
+            result.push_str(&convert(last));
+
+            result
+        }
+        None => convert(name),
+    }
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{RuleConfig, RuleError};
+
+/// Error returned when rules can't be loaded from a file.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file isn't a valid [RuleConfig](crate::RuleConfig) document.
+    Parse(serde_json::Error),
+    /// A rule in the file can't be added.
+    InvalidRule {
+        /// Pattern of the offending rule.
+        pattern: String,
+        /// Line of the file the pattern is on, starting at `1`.
+        line: Option<usize>,
+        /// Why the rule can't be added.
+        error: RuleError,
+    },
+    /// The global rules were [frozen](crate::freeze) and can't change anymore.
+    Frozen,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "can't read rules: {}", error),
+            LoadError::Parse(error) => write!(f, "can't parse rules: {}", error),
+            LoadError::InvalidRule {
+                pattern,
+                line: Some(line),
+                error,
+            } => write!(f, "rule `{}` on line {}: {}", pattern, line, error),
+            LoadError::InvalidRule { pattern, error, .. } => {
+                write!(f, "rule `{}`: {}", pattern, error)
+            }
+            LoadError::Frozen => write!(f, "{}", RuleError::Frozen),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Io(error) => Some(error),
+            LoadError::Parse(error) => Some(error),
+            LoadError::InvalidRule { error, .. } => Some(error),
+            LoadError::Frozen => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> Self {
+        LoadError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(error: serde_json::Error) -> Self {
+        LoadError::Parse(error)
+    }
+}
+
+/// Read a JSON rule configuration, along with the document to locate invalid rules in.
+pub(crate) fn read_config(path: &Path) -> Result<(String, RuleConfig), LoadError> {
+    let json = fs::read_to_string(path)?;
+    let config = serde_json::from_str(&json)?;
+
+    Ok((json, config))
+}
+
+/// Report a rule that can't be added, with the line of its pattern in the document.
+pub(crate) fn invalid_rule(json: &str, pattern: &str, error: RuleError) -> LoadError {
+    LoadError::InvalidRule {
+        pattern: pattern.to_string(),
+        line: find_line(json, pattern),
+        error,
+    }
+}
+
+/// Find the line of a string value in a JSON document.
+fn find_line(json: &str, value: &str) -> Option<usize> {
+    let quoted = serde_json::to_string(value).ok()?;
+    let offset = json.find(&quoted)?;
+
+    Some(json[..offset].matches('\n').count() + 1)
+}
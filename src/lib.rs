@@ -30,328 +30,1625 @@ fn main() {
 }
 ```
 
+# Thread safety
+
+The crate level functions share one set of rules behind a lock, so they can be called from any
+thread. A panic while the lock is held, e.g. in a [singular predicate](set_singular_predicate),
+doesn't poison the rules for good: the lock is recovered and later calls keep working.
+[Pluralizer] instances are `Send` and `Sync` and can be shared without any lock.
+
+With the `wasm` feature, meant for single-threaded WebAssembly, the rules are kept per thread
+without a lock instead.
+
  */
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod article;
+mod builder;
+#[cfg(feature = "cache")]
+mod cache;
+mod case;
+mod category;
+mod class;
+mod config;
 pub(crate) mod constants;
+mod count;
+mod counted;
+mod error;
+mod explain;
+mod gender;
+mod inflections;
+#[cfg(feature = "json")]
+mod json;
+pub mod locales;
+mod macros;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod numbers;
+mod pluralizer;
+pub mod prelude;
+mod rules;
+mod scope;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "default-rules"))]
+#[allow(clippy::module_inception, clippy::redundant_static_lifetimes)]
 mod test;
 
-use std::collections::HashMap;
-use std::sync::Mutex;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(any(feature = "thread-local", feature = "wasm"))]
+use core::cell::RefCell;
 
+#[cfg(all(feature = "std", not(feature = "wasm")))]
 use lazy_static::lazy_static;
 use regex::Regex;
+#[cfg(all(feature = "std", not(feature = "wasm")))]
+use std::sync::Mutex;
 
-#[derive(Debug, Clone)]
-struct WordRule {
-    rule: Regex,
-    placement: String,
+pub use article::Article;
+pub use builder::PluralizerBuilder;
+pub use case::CaseMode;
+pub use category::{plural_category, PluralCategory};
+pub use class::WordClass;
+pub use config::{RegexRule, RuleConfig};
+pub use count::Count;
+pub use counted::Counted;
+pub use error::RuleError;
+pub use explain::{RoundtripFailure, TransformSource, Transformation};
+pub use gender::{pluralize_gendered, Gender, GenderedNoun};
+pub use inflections::{camelize, dasherize, humanize, underscore, Inflection};
+#[cfg(feature = "json")]
+pub use json::LoadError;
+#[cfg(feature = "metrics")]
+pub use metrics::Path;
+pub use numbers::{
+    ordinalize, ordinalize_word, pluralize_grouped, pluralize_grouped_by, pluralize_with_zero,
+    pluralize_words,
+};
+pub use pluralizer::Pluralizer;
+pub use rules::Priority;
+pub use scope::{RuleScope, RuleSnapshot};
+
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
+#[cfg(all(feature = "std", not(feature = "wasm")))]
+lazy_static! {
+    static ref PLURALIZER: Mutex<Pluralizer> = Mutex::new(Pluralizer::new());
+}
+
+#[cfg(feature = "wasm")]
+thread_local! {
+    // WebAssembly in the browser runs on a single thread, so the rules don't need a lock.
+    static PLURALIZER: RefCell<Pluralizer> = RefCell::new(Pluralizer::new());
+}
+
+#[cfg(not(feature = "std"))]
+static PLURALIZER: spin::Lazy<spin::Mutex<Pluralizer>> =
+    spin::Lazy::new(|| spin::Mutex::new(Pluralizer::new()));
+
+// Immutable copy of the rules read without locking once they're frozen.
+#[cfg(feature = "std")]
+static FROZEN: std::sync::OnceLock<Pluralizer> = std::sync::OnceLock::new();
+
+#[cfg(not(feature = "std"))]
+static FROZEN: spin::Once<Pluralizer> = spin::Once::new();
+
+#[cfg(feature = "thread-local")]
+thread_local! {
+    // Rules of the current thread, copied from the shared rules on the first change.
+    static OVERLAY: RefCell<Option<Pluralizer>> = const { RefCell::new(None) };
 }
 
-macro_rules! load_regex_vec {
-    ($rules: expr, $uncountable: expr) => {{
-        let mut vec = $rules
-            .iter()
-            .map(|(k, v)| WordRule {
-                rule: Regex::new(k).expect("Invalid regular expression"),
-                placement: v.to_string(),
-            })
-            .collect::<Vec<WordRule>>();
+#[cfg(feature = "std")]
+fn with_shared_pluralizer<T>(f: impl FnOnce(&Pluralizer) -> T) -> T {
+    if let Some(frozen) = FROZEN.get() {
+        return f(frozen);
+    }
 
-        vec.append(
-            &mut $uncountable
-                .iter()
-                .map(|s| WordRule {
-                    rule: Regex::new(s).expect("Invalid regular expression"),
-                    placement: "$0".to_string(),
-                })
-                .collect(),
-        );
+    #[cfg(feature = "wasm")]
+    return PLURALIZER.with(|pluralizer| f(&pluralizer.borrow()));
 
-        vec
-    }};
+    #[cfg(not(feature = "wasm"))]
+    match PLURALIZER.lock() {
+        Ok(guard) => f(&guard),
+        Err(poisoned) => f(&poisoned.into_inner()),
+    }
 }
 
-macro_rules! load_irregular_map {
-    ($rules: expr, $map: expr) => {
-        $rules.iter().map($map).collect()
-    };
+#[cfg(all(feature = "std", not(feature = "thread-local")))]
+fn with_pluralizer<T>(f: impl FnOnce(&Pluralizer) -> T) -> T {
+    with_shared_pluralizer(f)
 }
 
-lazy_static! {
-    static ref IRREGULAR_SINGLES: Mutex<HashMap<String, String>> = Mutex::new(load_irregular_map!(
-        constants::IRREGULAR_RULES,
-        |(k, v)| (k.to_string(), v.to_string())
-    ));
-    static ref IRREGULAR_PLURALS: Mutex<HashMap<String, String>> = Mutex::new(load_irregular_map!(
-        constants::IRREGULAR_RULES,
-        |(k, v)| (v.to_string(), k.to_string())
-    ));
-    static ref PLURAL_RULES: Mutex<Vec<WordRule>> = Mutex::new(load_regex_vec!(
-        constants::PLURAL_RULES,
-        constants::UNCOUNTABLE_REGEX_RULES
-    ));
-    static ref SINGULAR_RULES: Mutex<Vec<WordRule>> = Mutex::new(load_regex_vec!(
-        constants::SINGULAR_RULES,
-        constants::UNCOUNTABLE_REGEX_RULES
-    ));
-    static ref UNCOUNTABLE_RULES: Mutex<Vec<String>> = Mutex::new(
-        constants::UNCOUNTABLE_RULES
-            .iter()
-            .map(|s| s.to_string())
-            .collect()
-    );
+#[cfg(feature = "thread-local")]
+fn with_pluralizer<T>(f: impl FnOnce(&Pluralizer) -> T) -> T {
+    OVERLAY.with(|overlay| match &*overlay.borrow() {
+        Some(pluralizer) => f(pluralizer),
+        None => with_shared_pluralizer(f),
+    })
 }
 
-/// Add an irregular word definition.
+#[cfg(not(feature = "std"))]
+fn with_pluralizer<T>(f: impl FnOnce(&Pluralizer) -> T) -> T {
+    match FROZEN.get() {
+        Some(frozen) => f(frozen),
+        None => f(&PLURALIZER.lock()),
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "thread-local")))]
+fn update_pluralizer<T>(f: impl FnOnce(&mut Pluralizer) -> T) -> T {
+    assert!(!rejects_changes(), "the rules are frozen");
+
+    #[cfg(feature = "wasm")]
+    return PLURALIZER.with(|pluralizer| f(&mut pluralizer.borrow_mut()));
+
+    #[cfg(not(feature = "wasm"))]
+    match PLURALIZER.lock() {
+        Ok(mut guard) => f(&mut guard),
+        Err(poisoned) => f(&mut poisoned.into_inner()),
+    }
+}
+
+#[cfg(feature = "thread-local")]
+fn update_pluralizer<T>(f: impl FnOnce(&mut Pluralizer) -> T) -> T {
+    OVERLAY.with(|overlay| {
+        let mut overlay = overlay.borrow_mut();
+
+        f(overlay.get_or_insert_with(|| with_shared_pluralizer(Pluralizer::clone)))
+    })
+}
+
+#[cfg(not(feature = "std"))]
+fn update_pluralizer<T>(f: impl FnOnce(&mut Pluralizer) -> T) -> T {
+    assert!(!rejects_changes(), "the rules are frozen");
+
+    f(&mut PLURALIZER.lock())
+}
+
+/// Change the rules like [update_pluralizer], but return an error instead of panicking once they
+/// are frozen.
+fn try_update_pluralizer<T>(
+    f: impl FnOnce(&mut Pluralizer) -> Result<T, RuleError>,
+) -> Result<T, RuleError> {
+    if rejects_changes() {
+        return Err(RuleError::Frozen);
+    }
+
+    update_pluralizer(f)
+}
+
+/// Check if changes to the global rules are rejected, thread-local rules can still change.
+fn rejects_changes() -> bool {
+    !cfg!(feature = "thread-local") && is_frozen()
+}
+
+/// Freeze the global rules, so reading them no longer takes a lock.
+///
+/// Meant for programs registering every rule at startup. Once frozen, the rules can't change for
+/// the rest of the program: the functions adding or changing rules panic, and the ones returning
+/// a [Result] return [RuleError::Frozen] instead, or `LoadError::Frozen` for files. With the
+/// `thread-local` feature each thread can still change its own copy of the frozen rules.
+///
+/// With the `cache` feature the cache still takes its own lock.
 ///
 /// # Examples
 /// ```
-/// pluralizer::add_irregular_rule("I".to_string(), "we".to_string());
+/// pluralizer::add_irregular_rule("cow".to_string(), "kine".to_string());
+/// pluralizer::freeze();
 ///
-/// let result = pluralizer::pluralize("I", 2, false); // we
+/// let result = pluralizer::pluralize("cow", 2, false); // kine
 /// ```
-pub fn add_irregular_rule(singular: String, plural: String) {
-    IRREGULAR_SINGLES
-        .lock()
-        .unwrap()
-        .insert(singular.to_string(), plural.to_string());
-    IRREGULAR_PLURALS
-        .lock()
-        .unwrap()
-        .insert(plural.to_string(), singular.to_string());
+pub fn freeze() {
+    #[cfg(feature = "std")]
+    FROZEN.get_or_init(|| with_shared_pluralizer(Pluralizer::clone));
+
+    #[cfg(not(feature = "std"))]
+    FROZEN.call_once(|| PLURALIZER.lock().clone());
 }
 
-/// Add a pluralization rule to the collection.
+/// Check if the global rules were [frozen](freeze).
 ///
-/// The rule argument must be a regular expression string.
+/// # Examples
+/// ```
+/// let result = pluralizer::is_frozen(); // false
+/// ```
+pub fn is_frozen() -> bool {
+    FROZEN.get().is_some()
+}
+
+/// The built-in irregular `(singular, plural)` pairs, whether or not the `default-rules` feature
+/// is enabled.
 ///
 /// # Examples
 /// ```
-/// use regex::Regex;
+/// let rules = pluralizer::default_irregular_rules();
+/// let result = rules.contains(&("goose", "geese")); // true
+/// ```
+pub fn default_irregular_rules() -> &'static [(&'static str, &'static str)] {
+    constants::IRREGULAR_RULES
+}
+
+/// The built-in `(pattern, placement)` pluralization rules, in the order they're added.
 ///
-/// pluralizer::add_plural_rule(Regex::new("(?i)(matr|cod|mur|sil|vert|ind|append)(?:ix|ex)$").unwrap(), "$1ices".to_string());
+/// # Examples
+/// ```
+/// let rules = pluralizer::default_plural_rules();
+/// let result = rules[0]; // ("(?i)s?$", "s")
+/// ```
+pub fn default_plural_rules() -> &'static [(&'static str, &'static str)] {
+    constants::PLURAL_RULES
+}
+
+/// The built-in `(pattern, placement)` singularization rules, in the order they're added.
 ///
-/// let result = pluralizer::pluralize("Vertex", 2, false); // Vertices
+/// # Examples
 /// ```
-pub fn add_plural_rule(rule: Regex, placement: String) {
-    PLURAL_RULES
-        .lock()
-        .unwrap()
-        .push(WordRule { rule, placement });
+/// let rules = pluralizer::default_singular_rules();
+/// let result = rules[0]; // ("(?i)s$", "")
+/// ```
+pub fn default_singular_rules() -> &'static [(&'static str, &'static str)] {
+    constants::SINGULAR_RULES
 }
 
-/// Add a singularization rule to the collection.
+/// The built-in uncountable words.
 ///
-/// The rule argument must be a regular expression string.
+/// # Examples
+/// ```
+/// let words = pluralizer::default_uncountable_rules();
+/// let result = words.contains(&"rice"); // true
+/// ```
+pub fn default_uncountable_rules() -> &'static [&'static str] {
+    constants::UNCOUNTABLE_RULES
+}
+
+/// The built-in uncountable regular expressions.
 ///
 /// # Examples
 /// ```
-/// use regex::Regex;
+/// let rules = pluralizer::default_uncountable_regex_rules();
+/// let result = rules.contains(&"(?i)sheep$"); // true
+/// ```
+pub fn default_uncountable_regex_rules() -> &'static [&'static str] {
+    constants::UNCOUNTABLE_REGEX_RULES
+}
+
+/// Add an irregular word definition.
 ///
-/// pluralizer::add_singular_rule(Regex::new("(?i)(matr|append)ices$").unwrap(), "$1ix".to_string());
+/// # Examples
+/// ```
+/// pluralizer::add_irregular_rule("I".to_string(), "we".to_string());
 ///
-/// let result = pluralizer::pluralize("Matrices", 1, false); // Matrix
+/// let result = pluralizer::pluralize("I", 2, false); // we
 /// ```
-pub fn add_singular_rule(rule: Regex, placement: String) {
-    SINGULAR_RULES
-        .lock()
-        .unwrap()
-        .push(WordRule { rule, placement });
+pub fn add_irregular_rule(singular: String, plural: String) {
+    update_pluralizer(|p| p.add_irregular_rule(singular, plural))
 }
 
-/// Uncountable rule struct
+/// Add a word whose singular and plural forms are identical, like "species".
 ///
-/// It's given as a parameter of [add_uncountable_rule](add_uncountable_rule) method
-pub enum UncountableRule {
-    Regex(Regex),
-    String(String),
+/// Unlike [uncountable](add_uncountable_rule) words, invariant words are still counted things
+/// ("2 species"), so [is_uncountable](is_uncountable) keeps returning `false` for them.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_invariant_rule("aircraft");
+///
+/// let result = pluralizer::pluralize("aircraft", 2, true); // 2 aircraft
+/// ```
+pub fn add_invariant_rule(word: &str) {
+    update_pluralizer(|p| p.add_invariant_rule(word))
 }
 
-/// Add an uncountable word rule.
+/// Prefix a singular word with "a" or "an" based on its first sound.
 ///
-/// The rule can be either a word or a RegEx using [the rule struct](UncountableRule)
+/// Common words whose spelling hides their first sound, like "hour" or "unit", are handled, and
+/// numbers get the article of how they're read, like "an 8" or "an 11". Words the heuristic gets
+/// wrong can be fixed with [add_article_rule](add_article_rule). Empty or blank words are returned
+/// unchanged.
 ///
 /// # Examples
 /// ```
-/// use pluralizer::UncountableRule;
+/// pluralizer::with_article("apple"); // an apple
+/// pluralizer::with_article("house"); // a house
+/// pluralizer::with_article("hour"); // an hour
+/// pluralizer::with_article("unicorn"); // a unicorn
+/// pluralizer::with_article("8"); // an 8
+/// ```
+pub fn with_article(word: &str) -> String {
+    with_pluralizer(|p| p.with_article(word))
+}
+
+/// Set the article used before a word, overriding the heuristic of [with_article](with_article).
 ///
-/// pluralizer::add_uncountable_rule(UncountableRule::String("cash".to_string()));
+/// # Examples
+/// ```
+/// use pluralizer::Article;
 ///
-/// let result = pluralizer::pluralize("Cash", 2, false); // Cash
+/// pluralizer::add_article_rule("FAQ", Article::An);
+///
+/// let result = pluralizer::with_article("FAQ"); // an FAQ
 /// ```
-pub fn add_uncountable_rule(rule: UncountableRule) {
-    match rule {
-        UncountableRule::Regex(rule) => {
-            // We add it as both plural and singular rules with same placement
-            add_plural_rule(rule.clone(), "$0".to_string());
-            add_singular_rule(rule, "$0".to_string());
-        }
-        UncountableRule::String(rule) => {
-            UNCOUNTABLE_RULES.lock().unwrap().push(rule.to_lowercase());
-        }
-    }
+pub fn add_article_rule(word: &str, article: Article) {
+    update_pluralizer(|p| p.add_article_rule(word, article))
 }
 
-fn restore_case(word: &str, token: &str) -> String {
-    // Tokens are an exact match.
-    if word.eq(token) {
-        return token.to_string();
-    }
-
-    // Lower cased words. E.g. "hello".
-    if word.eq(&word.to_lowercase()) {
-        return token.to_lowercase();
-    }
+/// Look up the collective noun for a group of the word, e.g. "pride" for "lion".
+///
+/// Both the singular and the plural of the word are accepted, and the collective is returned in
+/// lower case.
+///
+/// # Examples
+/// ```
+/// pluralizer::collective("fish"); // Some("school")
+/// pluralizer::collective("crows"); // Some("murder")
+/// pluralizer::collective("house"); // None
+/// ```
+pub fn collective(word: &str) -> Option<String> {
+    with_pluralizer(|p| p.collective(word))
+}
 
-    // Upper cased words. E.g. "WHISKY".
-    if word.eq(&word.to_uppercase()) {
-        return token.to_uppercase();
-    }
+/// Set the collective noun for a group of the word, overriding the built-in one.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_collective("kitten", "litter");
+///
+/// let result = pluralizer::pluralize_collective("kitten", 1); // Some("a litter of kittens")
+/// ```
+pub fn add_collective(word: &str, collective: &str) {
+    update_pluralizer(|p| p.add_collective(word, collective))
+}
 
-    // Title cased words. E.g. "Title".
-    let first_char = word.chars().nth(0);
+/// Name a group of the word with its collective noun, counting the groups.
+///
+/// A single group takes an [article](with_article) rather than the count. Returns `None` when the
+/// word has no [collective](collective).
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_collective("lion", 1); // Some("a pride of lions")
+/// pluralizer::pluralize_collective("fish", 3); // Some("3 schools of fish")
+/// pluralizer::pluralize_collective("house", 2); // None
+/// ```
+pub fn pluralize_collective(word: &str, count: isize) -> Option<String> {
+    with_pluralizer(|p| p.pluralize_collective(word, count))
+}
 
-    if let Some(fc) = first_char {
-        if fc.is_uppercase() {
-            let token_first_char = token.chars().nth(0);
+/// Add several irregular word definitions, locking the rules once.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_irregular_rules([
+///     ("cow".to_string(), "kine".to_string()),
+///     ("brother".to_string(), "brethren".to_string()),
+/// ]);
+///
+/// let result = pluralizer::pluralize("brother", 2, false); // brethren
+/// ```
+pub fn add_irregular_rules<I: IntoIterator<Item = (String, String)>>(pairs: I) {
+    update_pluralizer(|p| p.add_irregular_rules(pairs))
+}
 
-            if let Some(tfc) = token_first_char {
-                let last = if token.len() > 1 {
-                    &token[1..token.len()]
-                } else {
-                    ""
-                };
+/// Remove an irregular word definition by its singular form.
+///
+/// Both the singular and plural mappings are removed, returning whether anything was removed.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_irregular_rule("person".to_string(), "persons".to_string());
+/// pluralizer::remove_irregular_rule("person");
+///
+/// let result = pluralizer::pluralize("person", 2, false); // people
+/// ```
+pub fn remove_irregular_rule(singular: &str) -> bool {
+    update_pluralizer(|p| p.remove_irregular_rule(singular))
+}
 
-                return format!("{}{}", tfc, last);
-            }
-        }
-    }
+/// Look up the irregular plural registered for a singular word.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::get_irregular_plural("Echo"); // Some("echoes")
+/// ```
+pub fn get_irregular_plural(singular: &str) -> Option<String> {
+    with_pluralizer(|p| p.get_irregular_plural(singular))
+}
 
-    // Lower cased words. E.g. "test".
-    token.to_lowercase()
+/// Look up the irregular singular registered for a plural word.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::get_irregular_singular("tornadoes"); // Some("tornado")
+/// ```
+pub fn get_irregular_singular(plural: &str) -> Option<String> {
+    with_pluralizer(|p| p.get_irregular_singular(plural))
 }
 
-fn sanitize_word(token: String, word: &str, rules: Vec<WordRule>) -> String {
-    let uncountable = get_mutex(&UNCOUNTABLE_RULES);
+/// Snapshot every irregular `(singular, plural)` pair, sorted by the singular word.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_irregular_rule("octopus".to_string(), "octopodes".to_string());
+///
+/// let rules = pluralizer::irregular_rules();
+/// let result = rules.contains(&("octopus".to_string(), "octopodes".to_string())); // true
+/// ```
+pub fn irregular_rules() -> Vec<(String, String)> {
+    with_pluralizer(|p| p.irregular_rules())
+}
 
-    // Empty string or doesn't need fixing.
-    if token.len() == 0 || uncountable.contains(&token) {
-        return word.to_string();
-    }
+/// Add a pluralization rule to the collection.
+///
+/// The rule argument must be a regular expression string. Every `$n` reference in the placement
+/// must have a matching capture group in the rule, otherwise a [RuleError](RuleError) is returned.
+/// References take every following digit, so `$12` is group 12, and `${1}2` is group 1 followed
+/// by `2`.
+///
+/// # Examples
+/// ```
+/// use regex::Regex;
+///
+/// pluralizer::add_plural_rule(Regex::new("(?i)(matr|cod|mur|sil|vert|ind|append)(?:ix|ex)$").unwrap(), "$1ices".to_string()).unwrap();
+///
+/// let result = pluralizer::pluralize("Vertex", 2, false); // Vertices
+/// ```
+pub fn add_plural_rule(rule: Regex, placement: String) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.add_plural_rule(rule, placement))
+}
 
-    // Iterate over the sanitization rules and use the first one to match.
-    for word_rule in rules.iter().rev() {
-        if word_rule.rule.is_match(word) {
-            let str = word_rule.rule.replace(word, |caps: &regex::Captures| {
-                let mut str = restore_case(word, &word_rule.placement);
-
-                for (i, m) in caps
-                    .iter()
-                    .filter(|m| m.is_some())
-                    .map(|m| m.unwrap())
-                    .enumerate()
-                {
-                    str = str.replace(
-                        format!("${}", i).as_str(),
-                        restore_case(word, m.as_str()).as_str(),
-                    );
-                }
-
-                str
-            });
-
-            let mut skip = false;
-
-            return str
-                .chars()
-                .filter(|c| {
-                    if skip {
-                        skip = false;
-
-                        return skip;
-                    }
-
-                    skip = c == &'$';
-
-                    !skip
-                })
-                .collect();
-        }
-    }
+/// Add a pluralization rule, choosing whether it takes precedence over the existing rules.
+///
+/// [Priority::Last](Priority::Last) is the same as [add_plural_rule](add_plural_rule), while
+/// [Priority::First](Priority::First) adds a fallback used only when no other rule matches.
+///
+/// # Examples
+/// ```
+/// use pluralizer::Priority;
+/// use regex::Regex;
+///
+/// // The built-in rules still turn "cactus" into "cacti".
+/// pluralizer::add_plural_rule_with_priority(Regex::new("(?i)us$").unwrap(), "uses".to_string(), Priority::First).unwrap();
+///
+/// let result = pluralizer::pluralize("cactus", 2, false); // cacti
+/// let result = pluralizer::pluralize("campus", 2, false); // campuses
+/// ```
+pub fn add_plural_rule_with_priority(
+    rule: Regex,
+    placement: String,
+    priority: Priority,
+) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.add_plural_rule_with_priority(rule, placement, priority))
+}
 
-    word.to_string()
+/// Compile and add a pluralization rule to the collection.
+///
+/// Like [add_plural_rule](add_plural_rule), but an invalid pattern is returned as
+/// [RuleError::InvalidRegex](RuleError::InvalidRegex) instead of having to be compiled beforehand.
+///
+/// # Examples
+/// ```
+/// pluralizer::try_add_plural_rule("(?i)(ox)$", "$1en".to_string()).unwrap();
+///
+/// let result = pluralizer::pluralize("ox", 2, false); // oxen
+/// let result = pluralizer::try_add_plural_rule("(?i)(ox$", "$1en".to_string()); // Err(..)
+/// ```
+pub fn try_add_plural_rule(pattern: &str, placement: String) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.try_add_plural_rule(pattern, placement))
 }
 
-fn replace_word(
-    replace_map: HashMap<String, String>,
-    keep_map: HashMap<String, String>,
-    rules: Vec<WordRule>,
-    word: &str,
-) -> String {
-    // Get the correct token and case restoration functions.
-    let token = word.to_lowercase();
+/// Add a pluralization rule, rejecting patterns not anchored at the end of the word.
+///
+/// Like [add_plural_rule](add_plural_rule), but a pattern that doesn't end with `$` returns
+/// [RuleError::Unanchored](RuleError::Unanchored), since a rule matching the middle of a word
+/// would replace that part of it.
+///
+/// # Examples
+/// ```
+/// use regex::Regex;
+///
+/// pluralizer::add_plural_rule_checked(Regex::new("(?i)(ox)$").unwrap(), "$1en".to_string()).unwrap();
+///
+/// let result = pluralizer::add_plural_rule_checked(Regex::new("(?i)ox").unwrap(), "oxen".to_string());
+/// // Err(RuleError::Unanchored)
+/// ```
+pub fn add_plural_rule_checked(rule: Regex, placement: String) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.add_plural_rule_checked(rule, placement))
+}
 
-    // Check against the keep object map.
-    if keep_map.contains_key(&token) {
-        return restore_case(word, &token);
-    }
+/// Add a singularization rule to the collection.
+///
+/// The rule argument must be a regular expression string. Every `$n` reference in the placement
+/// must have a matching capture group in the rule, otherwise a [RuleError](RuleError) is returned.
+///
+/// # Examples
+/// ```
+/// use regex::Regex;
+///
+/// pluralizer::add_singular_rule(Regex::new("(?i)(matr|append)ices$").unwrap(), "$1ix".to_string()).unwrap();
+///
+/// let result = pluralizer::pluralize("Matrices", 1, false); // Matrix
+/// ```
+pub fn add_singular_rule(rule: Regex, placement: String) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.add_singular_rule(rule, placement))
+}
 
-    // Check against the replacement map for a direct word replacement.
-    if let Some(token) = replace_map.get(&*token) {
-        return restore_case(word, token);
-    }
+/// Add a singularization rule, choosing whether it takes precedence over the existing rules.
+///
+/// [Priority::Last](Priority::Last) is the same as [add_singular_rule](add_singular_rule), while
+/// [Priority::First](Priority::First) adds a fallback used only when no other rule matches.
+///
+/// # Examples
+/// ```
+/// use pluralizer::Priority;
+/// use regex::Regex;
+///
+/// pluralizer::add_singular_rule_with_priority(Regex::new("(?i)uses$").unwrap(), "us".to_string(), Priority::First).unwrap();
+///
+/// let result = pluralizer::pluralize("buses", 1, false); // bus
+/// ```
+pub fn add_singular_rule_with_priority(
+    rule: Regex,
+    placement: String,
+    priority: Priority,
+) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.add_singular_rule_with_priority(rule, placement, priority))
+}
 
-    // Run all the rules against the word.
-    sanitize_word(token, word, rules)
+/// Compile and add a singularization rule to the collection.
+///
+/// Like [add_singular_rule](add_singular_rule), but an invalid pattern is returned as
+/// [RuleError::InvalidRegex](RuleError::InvalidRegex) instead of having to be compiled beforehand.
+///
+/// # Examples
+/// ```
+/// pluralizer::try_add_singular_rule("(?i)(ox)en$", "$1".to_string()).unwrap();
+///
+/// let result = pluralizer::pluralize("oxen", 1, false); // ox
+/// ```
+pub fn try_add_singular_rule(pattern: &str, placement: String) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.try_add_singular_rule(pattern, placement))
 }
 
-fn to_singular(word: &str) -> String {
-    replace_word(
-        get_mutex(&IRREGULAR_PLURALS),
-        get_mutex(&IRREGULAR_SINGLES),
-        get_mutex(&SINGULAR_RULES),
-        word,
-    )
-}
-
-fn get_mutex<T: Sized + Clone>(var: &Mutex<T>) -> T {
-    match var.lock() {
-        Ok(guard) => guard,
-        Err(poisoned) => poisoned.into_inner(),
-    }
-    .clone()
+/// Add a singularization rule, rejecting patterns not anchored at the end of the word.
+///
+/// Like [add_singular_rule](add_singular_rule), but a pattern that doesn't end with `$` returns
+/// [RuleError::Unanchored](RuleError::Unanchored).
+///
+/// # Examples
+/// ```
+/// use regex::Regex;
+///
+/// pluralizer::add_singular_rule_checked(Regex::new("(?i)(ox)en$").unwrap(), "$1".to_string()).unwrap();
+/// ```
+pub fn add_singular_rule_checked(rule: Regex, placement: String) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.add_singular_rule_checked(rule, placement))
 }
 
-fn to_plural(word: &str) -> String {
-    replace_word(
-        get_mutex(&IRREGULAR_SINGLES),
-        get_mutex(&IRREGULAR_PLURALS),
-        get_mutex(&PLURAL_RULES),
-        word,
-    )
+/// Uncountable rule struct
+///
+/// It's given as a parameter of [add_uncountable_rule](add_uncountable_rule) method
+#[derive(Clone)]
+pub enum UncountableRule {
+    Regex(Regex),
+    String(String),
+    /// A word matched with its exact casing, e.g. "Polish" but not "polish".
+    StringExact(String),
 }
 
-/// Pluralize or singularize a word based on the passed in count.
+/// Add an uncountable word rule.
+///
+/// The rule can be either a word or a RegEx using [the rule struct](UncountableRule)
 ///
 /// # Examples
 /// ```
-/// pluralizer::pluralize("House", 2, true); // 2 Houses
-/// pluralizer::pluralize("Houses", 1, true); // 1 House
-/// pluralizer::pluralize("House", 1, false); // House
-/// pluralizer::pluralize("Houses", 2, false); // Houses
+/// use pluralizer::UncountableRule;
+///
+/// pluralizer::add_uncountable_rule(UncountableRule::String("cash".to_string()));
+///
+/// let result = pluralizer::pluralize("Cash", 2, false); // Cash
 /// ```
-pub fn pluralize(word: &str, count: isize, include_count: bool) -> String {
-    let pluralized: String = if count == 1 {
-        to_singular(word)
-    } else {
-        to_plural(word)
-    };
+pub fn add_uncountable_rule(rule: UncountableRule) {
+    update_pluralizer(|p| p.add_uncountable_rule(rule))
+}
+
+/// Compile and add an uncountable regex rule.
+///
+/// Like [add_uncountable_rule](add_uncountable_rule) with [UncountableRule::Regex], but an invalid
+/// pattern is returned as [RuleError::InvalidRegex](RuleError::InvalidRegex).
+///
+/// # Examples
+/// ```
+/// pluralizer::try_add_uncountable_rule("(?i)ware$").unwrap();
+///
+/// let result = pluralizer::pluralize("middleware", 2, false); // middleware
+/// ```
+pub fn try_add_uncountable_rule(pattern: &str) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.try_add_uncountable_rule(pattern))
+}
+
+/// Add several uncountable words, locking the rules once.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_uncountable_rules(["cash".to_string(), "feedback".to_string()]);
+///
+/// let result = pluralizer::pluralize("feedback", 2, false); // feedback
+/// ```
+pub fn add_uncountable_rules<I: IntoIterator<Item = String>>(words: I) {
+    update_pluralizer(|p| p.add_uncountable_rules(words))
+}
+
+/// Snapshot the words registered as uncountable.
+///
+/// Uncountable regex rules aren't included, use [is_uncountable](is_uncountable) to check them.
+///
+/// # Examples
+/// ```
+/// let words = pluralizer::uncountable_words();
+/// let result = words.contains(&"rice".to_string()); // true
+/// ```
+pub fn uncountable_words() -> Vec<String> {
+    with_pluralizer(|p| p.uncountable_words())
+}
+
+/// Check if a word is exempt from pluralization, by either an uncountable word or regex rule.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::is_uncountable("Sheep"); // true
+/// let result = pluralizer::is_uncountable("reindeer"); // true
+/// let result = pluralizer::is_uncountable("house"); // false
+/// ```
+pub fn is_uncountable(word: &str) -> bool {
+    with_pluralizer(|p| p.is_uncountable(word))
+}
+
+/// Restore the default rule set.
+///
+/// Every rule added through the `add_*` functions is discarded and the rules are reloaded from the
+/// built-in tables.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_irregular_rule("cow".to_string(), "kine".to_string());
+/// pluralizer::reset_rules();
+///
+/// let result = pluralizer::pluralize("cow", 2, false); // cows
+/// ```
+pub fn reset_rules() {
+    update_pluralizer(|p| *p = Pluralizer::new())
+}
+
+/// Add every rule of a [configuration](RuleConfig).
+///
+/// All the patterns are compiled and checked first, so nothing is added when any of them is
+/// invalid.
+///
+/// # Examples
+/// ```
+/// use pluralizer::RuleConfig;
+///
+/// let config = RuleConfig {
+///     uncountable: vec!["cash".to_string()],
+///     ..RuleConfig::default()
+/// };
+///
+/// pluralizer::load_config(&config).unwrap();
+///
+/// let result = pluralizer::pluralize("cash", 2, false); // cash
+/// ```
+pub fn load_config(config: &RuleConfig) -> Result<(), RuleError> {
+    try_update_pluralizer(|p| p.load_config(config))
+}
+
+/// Load a [configuration](RuleConfig) from a JSON file and add its rules.
+///
+/// Every pattern is checked before any rule is added, and an invalid one is reported with the line
+/// it's on. Requires the `json` feature.
+///
+/// # Examples
+/// ```no_run
+/// pluralizer::load_rules_from_json("vocabulary.json".as_ref()).unwrap();
+/// ```
+#[cfg(feature = "json")]
+pub fn load_rules_from_json(path: &std::path::Path) -> Result<(), LoadError> {
+    // Read the file before locking the rules.
+    let (json, config) = json::read_config(path)?;
+
+    if rejects_changes() {
+        return Err(LoadError::Frozen);
+    }
+
+    update_pluralizer(|p| p.load_config_rules(&config))
+        .map_err(|(pattern, error)| json::invalid_rule(&json, pattern, error))
+}
+
+/// Snapshot the current rules as a [configuration](RuleConfig).
+///
+/// Loading the configuration into an [empty](Pluralizer::empty) instance gives the same rules,
+/// except that uncountable regexes are moved after the other rules.
+///
+/// # Examples
+/// ```
+/// let config = pluralizer::export_config();
+/// let result = config.uncountable.contains(&"rice".to_string()); // true
+/// ```
+pub fn export_config() -> RuleConfig {
+    with_pluralizer(|p| p.export_config())
+}
+
+/// Start a scope of temporary rules, restored to their current state when the guard is dropped.
+///
+/// The guard records the rules it adds rather than holding the lock, so other threads see the
+/// scoped rules until it's dropped, unless the `thread-local` feature is enabled. Rules added
+/// outside the scope meanwhile are kept.
+///
+/// # Examples
+/// ```
+/// {
+///     let scope = pluralizer::scoped();
+///     scope.add_irregular_rule("cow".to_string(), "kine".to_string());
+///
+///     let result = pluralizer::pluralize("cow", 2, false); // kine
+/// }
+///
+/// let result = pluralizer::pluralize("cow", 2, false); // cows
+/// ```
+pub fn scoped() -> RuleScope {
+    RuleScope::new()
+}
 
+/// Capture the current contents of every rule collection.
+///
+/// # Examples
+/// ```
+/// let base = pluralizer::snapshot();
+///
+/// pluralizer::add_irregular_rule("cow".to_string(), "kine".to_string());
+/// pluralizer::restore(base);
+///
+/// let result = pluralizer::pluralize("cow", 2, false); // cows
+/// ```
+pub fn snapshot() -> RuleSnapshot {
+    with_pluralizer(|p| RuleSnapshot(p.clone()))
+}
+
+/// Replace every rule collection with a [snapshot](snapshot).
+///
+/// All the collections are swapped under a single lock, so no caller ever sees a partially
+/// restored set of rules.
+pub fn restore(snapshot: RuleSnapshot) {
+    update_pluralizer(|p| *p = snapshot.0)
+}
+
+/// Remove every rule added since the rules were created, keeping the built-in rules.
+///
+/// Irregular, uncountable, regex and every other kind of rule added through the `add_*` functions
+/// or [load_config](load_config) is removed, and built-in rules removed since are back. Settings
+/// like [set_classical_mode](set_classical_mode) are kept. The built-in regex rules aren't
+/// compiled again: each rule list remembers where its built-in rules start and how many there
+/// are, added rules being either before or after them.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_irregular_rule("cow".to_string(), "kine".to_string());
+/// pluralizer::clear_user_rules();
+///
+/// let result = pluralizer::plural("cow"); // cows
+/// let result = pluralizer::plural("goose"); // geese
+/// ```
+pub fn clear_user_rules() {
+    update_pluralizer(|p| p.clear_user_rules())
+}
+
+/// Add a word ending in `o` that only takes an `s` in the plural, e.g. "photos" or "pianos".
+///
+/// The built-in rules add `es` to a few endings like "potato" and "hero", these exceptions are
+/// checked before them.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_o_exception("burrito");
+///
+/// let result = pluralizer::pluralize("burrito", 2, false); // burritos
+/// ```
+pub fn add_o_exception(word: &str) {
+    update_pluralizer(|p| p.add_o_exception(word))
+}
+
+/// Add a contraction that's never pluralized or singularized, e.g. "y'all".
+///
+/// Contractions like "it's" or "don't" look like possessives, the built-in ones are returned
+/// unchanged while possessives like "cat's" still become "cats'". Matching ignores the case and
+/// accepts both straight and curly apostrophes.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_contraction("y'all");
+///
+/// let result = pluralizer::plural("y'all"); // y'all
+/// ```
+pub fn add_contraction(word: &str) {
+    update_pluralizer(|p| p.add_contraction(word))
+}
+
+/// Add a preposition that marks the end of the head noun in a phrase.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_preposition("sans".to_string());
+///
+/// let result = pluralizer::pluralize_phrase("cat sans hat", 2); // cats sans hat
+/// ```
+pub fn add_preposition(preposition: String) {
+    update_pluralizer(|p| p.add_preposition(preposition))
+}
+
+/// Add a word that is placed after the head noun in a phrase.
+///
+/// # Examples
+/// ```
+/// pluralizer::add_postpositive("royal".to_string());
+///
+/// let result = pluralizer::pluralize_phrase("battle royal", 2); // battles royal
+/// ```
+pub fn add_postpositive(postpositive: String) {
+    update_pluralizer(|p| p.add_postpositive(postpositive))
+}
+
+/// Count the plural and singular rules, including the built-in ones.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::rule_count() > 0; // true
+/// ```
+pub fn rule_count() -> usize {
+    with_pluralizer(|p| p.rule_count())
+}
+
+/// Limit the number of plural and singular rules, `None` removes the limit.
+///
+/// Once the limit is reached [add_plural_rule](add_plural_rule),
+/// [add_singular_rule](add_singular_rule) and their variants return
+/// [RuleError::TooManyRules](RuleError::TooManyRules). The limit includes the built-in rules, and
+/// uncountable regex rules can always be added.
+///
+/// # Examples
+/// ```
+/// use regex::Regex;
+///
+/// pluralizer::set_max_rules(Some(pluralizer::rule_count()));
+///
+/// let result = pluralizer::add_plural_rule(Regex::new("(?i)(ox)$").unwrap(), "$1en".to_string());
+/// // Err(RuleError::TooManyRules { .. })
+/// ```
+pub fn set_max_rules(max: Option<usize>) {
+    update_pluralizer(|p| p.set_max_rules(max))
+}
+
+/// Limit the length in bytes of the words to replace, `None` removes the limit.
+///
+/// Longer words are returned unchanged before any lowercasing or regex work, which bounds the
+/// cost of pluralizing untrusted input. There's no limit by default.
+///
+/// # Examples
+/// ```
+/// pluralizer::set_max_word_len(Some(64));
+///
+/// pluralizer::plural("house"); // houses
+/// pluralizer::plural(&"a".repeat(65)); // unchanged
+/// ```
+pub fn set_max_word_len(max: Option<usize>) {
+    update_pluralizer(|p| p.set_max_word_len(max))
+}
+
+/// Call a hook with the branch that produced each converted word, e.g. to count the words no rule
+/// matched.
+///
+/// The hook replaces the previous one, and runs while the rules are locked so it shouldn't call the
+/// crate level functions. With the `cache` feature, words served from the cache aren't reported
+/// again.
+///
+/// # Examples
+/// ```
+/// use pluralizer::Path;
+///
+/// pluralizer::set_metrics_hook(|path| {
+///     if path == Path::Unchanged {
+///         // Count the word as missing a rule.
+///     }
+/// });
+/// ```
+#[cfg(feature = "metrics")]
+pub fn set_metrics_hook(hook: impl Fn(Path) + Send + Sync + 'static) {
+    update_pluralizer(|p| p.set_metrics_hook(hook))
+}
+
+/// Wrap the counts in Unicode bidi isolates, so they render correctly within right-to-left text.
+///
+/// When enabled, [pluralize](fn@pluralize) and the other functions placing a numeric count next to the
+/// word surround the count with a left-to-right isolate (`U+2066`) and a pop directional isolate
+/// (`U+2069`). Counts spelled out in words are left as is. It's disabled by default.
+///
+/// # Examples
+/// ```
+/// pluralizer::set_bidi_isolation(true);
+///
+/// pluralizer::pluralize("house", 2, true); // "\u{2066}2\u{2069} houses"
+/// ```
+pub fn set_bidi_isolation(enabled: bool) {
+    update_pluralizer(|p| p.set_bidi_isolation(enabled))
+}
+
+/// Decide which counts take the singular form.
+///
+/// By default only `1` and `-1` are singular. Every count based function uses the predicate,
+/// except [pluralize_f64](pluralize_f64).
+///
+/// # Examples
+/// ```
+/// // French treats zero as singular.
+/// pluralizer::set_singular_predicate(|n| n == 0 || n == 1);
+///
+/// let result = pluralizer::pluralize("House", 0, true); // 0 House
+/// ```
+pub fn set_singular_predicate(predicate: fn(isize) -> bool) {
+    update_pluralizer(|p| p.set_singular_predicate(predicate))
+}
+
+/// Only convert words with an irregular rule, disabled by default.
+///
+/// In strict mode the regular expression rules are skipped, so unknown words like product names
+/// are returned unchanged instead of being guessed. Words are converted only when they were taught
+/// through [add_irregular_rule](add_irregular_rule), including the built-in irregular words.
+///
+/// # Examples
+/// ```
+/// pluralizer::set_strict_mode(true);
+///
+/// let result = pluralizer::pluralize("Kubernetes", 1, false); // Kubernetes
+/// let result = pluralizer::pluralize("goose", 2, false); // geese
+/// ```
+pub fn set_strict_mode(enabled: bool) {
+    update_pluralizer(|p| p.set_strict_mode(enabled))
+}
+
+/// Keep acronyms upper cased while adding a lower cased suffix, enabled by default.
+///
+/// Acronyms are all caps words of two to four letters or digits. The suffix is only lower cased
+/// when it's appended to the whole word, so "URL" becomes "URLs" but "BUS" still becomes "BUSES".
+/// When disabled, upper cased words take an upper cased suffix like any other word.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::pluralize("URL", 2, false); // URLs
+///
+/// pluralizer::set_acronym_mode(false);
+/// let result = pluralizer::pluralize("URL", 2, false); // URLS
+/// ```
+pub fn set_acronym_mode(enabled: bool) {
+    update_pluralizer(|p| p.set_acronym_mode(enabled))
+}
+
+/// Prefer classical Latin and Greek plurals over the anglicized ones, disabled by default.
+///
+/// Affects antenna, apex, aquarium, bureau, consortium, corpus, cranium, emporium, formula, forum,
+/// genius, hippopotamus, larva, maximum, medium, memorandum, minimum, moratorium, nebula, octopus,
+/// optimum, opus, plateau, referendum, soprano, stadium, thesaurus, ultimatum, virtuoso and vortex.
+/// Irregular rules added with [add_irregular_rule](add_irregular_rule) still take precedence.
+///
+/// Latin neuters that are only used with `-a`, like bacterium, curriculum, datum and millennium,
+/// always take it. Ambiguous words default to `s`: forum becomes forums and medium becomes
+/// mediums, while media is kept uncountable as in "the media" unless classical mode is enabled.
+///
+/// # Examples
+/// ```
+/// pluralizer::set_classical_mode(true);
+/// let result = pluralizer::plural("formula"); // formulae
+///
+/// pluralizer::set_classical_mode(false);
+/// let result = pluralizer::plural("formula"); // formulas
+/// ```
+pub fn set_classical_mode(enabled: bool) {
+    update_pluralizer(|p| p.set_classical_mode(enabled))
+}
+
+/// Set how many transformed words are remembered, `0` disables the cache.
+///
+/// Results are cached per word until the rules change. Requires the `cache` feature.
+///
+/// # Examples
+/// ```
+/// pluralizer::set_cache_capacity(4096);
+/// ```
+#[cfg(feature = "cache")]
+pub fn set_cache_capacity(capacity: usize) {
+    update_pluralizer(|p| p.set_cache_capacity(capacity))
+}
+
+fn to_singular(word: &str) -> String {
+    with_pluralizer(|p| p.singular(word))
+}
+
+fn to_plural(word: &str) -> String {
+    with_pluralizer(|p| p.plural(word))
+}
+
+/// Pluralize or singularize a word based on the passed in count.
+///
+/// Counts of `1` and `-1` are singular ("minus one degree"), every other count is plural.
+///
+/// Hyphenated compounds pluralize their principal word, e.g. "mother-in-law" becomes
+/// "mothers-in-law".
+///
+/// Empty and whitespace-only words are returned unchanged, and only the count is returned when
+/// including it, e.g. "2" rather than "2 ".
+///
+/// The count can be of any integer [type](Count), so `u32` or `usize` counts don't need a cast.
+///
+/// Passing `true` is the same as calling [pluralize_with_count](pluralize_with_count) and passing
+/// `false` the same as [pluralize_word](pluralize_word), which read better at call sites.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize("House", 2, true); // 2 Houses
+/// pluralizer::pluralize("Houses", 1, true); // 1 House
+/// pluralizer::pluralize("House", 1, false); // House
+/// pluralizer::pluralize("Houses", 2, false); // Houses
+///
+/// let houses = vec!["Tudor", "Victorian"];
+/// pluralizer::pluralize("House", houses.len(), true); // 2 Houses
+/// ```
+pub fn pluralize(word: &str, count: impl Count, include_count: bool) -> String {
     if include_count {
-        format!("{} {}", count, pluralized)
+        pluralize_with_count(word, count)
     } else {
-        pluralized
+        pluralize_word(word, count)
     }
 }
+
+/// Pluralize or singularize a word based on the passed in count, including the count.
+///
+/// Same as [pluralize](fn@pluralize) with `include_count` set to `true`.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_with_count("House", 2); // 2 Houses
+/// pluralizer::pluralize_with_count("Houses", 1); // 1 House
+/// ```
+pub fn pluralize_with_count(word: &str, count: impl Count) -> String {
+    with_pluralizer(|p| p.pluralize_with_count(word, count))
+}
+
+/// Pluralize or singularize a word based on the passed in count, without including the count.
+///
+/// Same as [pluralize](fn@pluralize) with `include_count` set to `false`.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_word("House", 2); // Houses
+/// pluralizer::pluralize_word("Houses", 1); // House
+/// ```
+pub fn pluralize_word(word: &str, count: impl Count) -> String {
+    with_pluralizer(|p| p.pluralize_word(word, count))
+}
+
+/// Pluralize or singularize a word based on the passed in count, using the given rules instead of
+/// the shared ones.
+///
+/// Nothing global is read or locked, so the result only depends on the arguments. The
+/// [Pluralizer](Pluralizer) is owned by the caller and can be configured independently of the
+/// crate level functions, e.g. one per language.
+///
+/// # Examples
+/// ```
+/// use pluralizer::Pluralizer;
+///
+/// let mut rules = Pluralizer::new();
+/// rules.add_irregular_rule("cow".to_string(), "kine".to_string());
+///
+/// pluralizer::pluralize_with_rules("cow", 2, &rules); // kine
+/// pluralizer::pluralize("cow", 2, false); // cows
+/// ```
+pub fn pluralize_with_rules(word: &str, count: impl Count, rules: &Pluralizer) -> String {
+    rules.pluralize_word(word, count)
+}
+
+/// Pluralize or singularize a word based on the passed in count, with a custom separator between
+/// the count and the word.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_with_separator("House", 2, "\u{00A0}"); // 2\u{00A0}Houses
+/// pluralizer::pluralize_with_separator("kg", 5, ""); // 5kgs
+/// ```
+pub fn pluralize_with_separator(word: &str, count: isize, separator: &str) -> String {
+    with_pluralizer(|p| p.pluralize_with_separator(word, count, separator))
+}
+
+/// Pluralize or singularize a word based on the passed in count, forcing the casing of the result.
+///
+/// # Examples
+/// ```
+/// use pluralizer::CaseMode;
+///
+/// pluralizer::pluralize_cased("user_role", 2, CaseMode::Title); // User_roles
+/// pluralizer::pluralize_cased("House", 2, CaseMode::Upper); // HOUSES
+/// pluralizer::pluralize_cased("House", 2, CaseMode::Preserve); // Houses
+/// pluralizer::pluralize_cased("GeeSE", 2, CaseMode::Keep); // GeeSE
+/// ```
+pub fn pluralize_cased(word: &str, count: isize, case: CaseMode) -> String {
+    with_pluralizer(|p| p.pluralize_cased(word, count, case))
+}
+
+/// Pluralize or singularize a word based on the passed in count, followed by the count.
+///
+/// The word and count are always separated by a single space. For any other separator
+/// [pluralize](fn@pluralize) the word without its count and append the count yourself.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_count_after("House", 5); // Houses 5
+/// pluralizer::pluralize_count_after("Houses", 1); // House 1
+/// ```
+pub fn pluralize_count_after(word: &str, count: isize) -> String {
+    with_pluralizer(|p| p.pluralize_count_after(word, count))
+}
+
+/// Prefix a unit with a value, pluralizing spelled out units like [pluralize_f64](pluralize_f64).
+///
+/// Unit symbols, e.g. "kg", "km/h" or "MB", are the same for any value and kept unchanged. Symbols
+/// are matched with their exact casing, and more can be added with
+/// [add_unit_symbol](add_unit_symbol).
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_unit(5.0, "kg"); // 5 kg
+/// pluralizer::pluralize_unit(5.0, "kilogram"); // 5 kilograms
+/// pluralizer::pluralize_unit(1.0, "kilograms"); // 1 kilogram
+/// pluralizer::pluralize_unit(2.5, "km/h"); // 2.5 km/h
+/// ```
+pub fn pluralize_unit(value: f64, unit: &str) -> String {
+    with_pluralizer(|p| p.pluralize_unit(value, unit))
+}
+
+/// Add a unit symbol that is kept unchanged by [pluralize_unit](pluralize_unit).
+///
+/// # Examples
+/// ```
+/// pluralizer::add_unit_symbol("dpi".to_string());
+///
+/// let result = pluralizer::pluralize_unit(300.0, "dpi"); // 300 dpi
+/// ```
+pub fn add_unit_symbol(symbol: String) {
+    update_pluralizer(|p| p.add_unit_symbol(symbol))
+}
+
+/// Pluralize or singularize a word that may already start with a count, replacing that count.
+///
+/// A leading integer and the whitespace after it are stripped before pluralizing, so round-tripped
+/// strings don't get a second count. When the leading token isn't an integer the whole string is
+/// the word.
+///
+/// # Examples
+/// ```
+/// pluralizer::repluralize("1 house", 3); // 3 houses
+/// pluralizer::repluralize("3 apples", 1); // 1 apple
+/// pluralizer::repluralize("house", 2); // 2 houses
+/// pluralizer::repluralize("3rd house", 2); // 2 3rd houses
+/// ```
+pub fn repluralize(word_with_count: &str, count: isize) -> String {
+    with_pluralizer(|p| p.repluralize(word_with_count, count))
+}
+
+/// Split the singular and plural forms of a word into their differing suffixes.
+///
+/// Both suffixes follow the stem the forms share, so a template can render the stem once and
+/// append either suffix. Irregular forms that don't share a stem, e.g. "person" and "people", are
+/// returned as whole words.
+///
+/// # Examples
+/// ```
+/// pluralizer::suffix_delta("box"); // ("", "es")
+/// pluralizer::suffix_delta("babies"); // ("y", "ies")
+/// pluralizer::suffix_delta("sheep"); // ("", "")
+/// pluralizer::suffix_delta("person"); // ("person", "people")
+/// ```
+pub fn suffix_delta(word: &str) -> (String, String) {
+    with_pluralizer(|p| p.suffix_delta(word))
+}
+
+/// Pluralize or singularize a word based on the passed in count, split into its stem and suffix.
+///
+/// The stem is shared by the singular and plural forms and the suffix is the inflected rest, so
+/// a UI can style the suffix on its own. Joining both gives the same word as
+/// [pluralize_word](pluralize_word). Irregular forms that don't share a stem, e.g. "person" and
+/// "people", are returned whole with an empty suffix.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_parts("box", 2); // ("box", "es")
+/// pluralizer::pluralize_parts("baby", 2); // ("bab", "ies")
+/// pluralizer::pluralize_parts("babies", 1); // ("bab", "y")
+/// pluralizer::pluralize_parts("person", 2); // ("people", "")
+/// ```
+pub fn pluralize_parts(word: &str, count: impl Count) -> (String, String) {
+    with_pluralizer(|p| p.pluralize_parts(word, count))
+}
+
+/// Compute both forms of a word at once, to pick one by count later without locking the rules.
+///
+/// # Examples
+/// ```
+/// let house = pluralizer::inflect("house");
+///
+/// house.for_count(1); // house
+/// house.for_count(2); // houses
+/// ```
+pub fn inflect(word: &str) -> Inflection {
+    with_pluralizer(|p| p.inflect(word))
+}
+
+/// Pluralize or singularize a word based on a floating-point count.
+///
+/// Only a count exactly equal to `1.0` or `-1.0` is singular, every other value is plural,
+/// including `0.5`, `0.9999`, `1.0000001` and `NaN`. No rounding is applied, so values computed with floating-point
+/// arithmetic should be rounded by the caller first.
+///
+/// Whole numbers are printed without a fractional part when the count is included.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_f64("Liter", 0.5, true); // 0.5 Liters
+/// pluralizer::pluralize_f64("Liters", 1.0, true); // 1 Liter
+/// pluralizer::pluralize_f64("Liter", 2.0, true); // 2 Liters
+/// ```
+pub fn pluralize_f64(word: &str, count: f64, include_count: bool) -> String {
+    with_pluralizer(|p| p.pluralize_f64(word, count, include_count))
+}
+
+/// Pluralize or singularize a word based on the passed in count without the count prefix.
+///
+/// The word is borrowed when it is already in the requested form, avoiding an allocation for
+/// uncountable words and words that don't need fixing.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+///
+/// let result = pluralizer::pluralize_cow("Houses", 2); // Cow::Borrowed("Houses")
+/// let result = pluralizer::pluralize_cow("House", 2); // Cow::Owned("Houses")
+/// ```
+pub fn pluralize_cow(word: &str, count: isize) -> Cow<'_, str> {
+    with_pluralizer(|p| p.pluralize_cow(word, count))
+}
+
+/// Pluralize or singularize a word for a range of counts, prefixing it with the range.
+///
+/// The word is singular only when both ends of the range take the singular, so "1–2 houses" is
+/// plural. The ends are joined by an en dash, and a range with equal ends is written as a single
+/// count.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_range("house", 2, 5); // 2–5 houses
+/// pluralizer::pluralize_range("house", 1, 2); // 1–2 houses
+/// pluralizer::pluralize_range("houses", 1, 1); // 1 house
+/// ```
+pub fn pluralize_range(word: &str, low: isize, high: isize) -> String {
+    with_pluralizer(|p| p.pluralize_range(word, low, high))
+}
+
+/// Pluralize or singularize the head noun of a phrase based on the passed in count.
+///
+/// The head noun is the word before the first preposition, e.g. "box of chocolates", or before a
+/// postpositive, e.g. "attorney general". Otherwise the last word is used.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_phrase("box of chocolates", 2); // boxes of chocolates
+/// pluralizer::pluralize_phrase("attorney general", 2); // attorneys general
+/// pluralizer::pluralize_phrase("chocolate box", 2); // chocolate boxes
+/// ```
+pub fn pluralize_phrase(phrase: &str, count: isize) -> String {
+    with_pluralizer(|p| p.pluralize_phrase(phrase, count))
+}
+
+/// Pick the verb agreeing with the count, following the same rule as [pluralize](fn@pluralize).
+///
+/// Common verbs and determiners like "is", "was", "has" and "this" are also irregular rules, so
+/// [pluralize_word](pluralize_word) turns them into "are", "were", "have" and "these" without
+/// listing both forms.
+///
+/// # Examples
+/// ```
+/// let houses = 2;
+///
+/// format!(
+///     "{} {}",
+///     pluralizer::pluralize_with_count("house", houses),
+///     pluralizer::verb_for_count("is", "are", houses)
+/// ); // 2 houses are
+/// pluralizer::verb_for_count("goes", "go", 1); // goes
+/// pluralizer::pluralize_word("was", 2); // were
+/// ```
+pub fn verb_for_count<'a>(singular_verb: &'a str, plural_verb: &'a str, count: isize) -> &'a str {
+    with_pluralizer(|p| p.verb_for_count(singular_verb, plural_verb, count))
+}
+
+/// Prefix a subject with "this" or "these" depending on the count, pluralizing it to agree.
+///
+/// The subject is pluralized as a [phrase](pluralize_phrase), so only its head noun changes.
+///
+/// # Examples
+/// ```
+/// pluralizer::agree("house", 1); // this house
+/// pluralizer::agree("houses", 2); // these houses
+/// pluralizer::agree("box of chocolates", 3); // these boxes of chocolates
+/// ```
+pub fn agree(subject: &str, count: isize) -> String {
+    with_pluralizer(|p| p.agree(subject, count))
+}
+
+/// Pluralize or singularize the words of a text for which the predicate returns `true`.
+///
+/// The text is split into words, letters and digits optionally joined by apostrophes or hyphens,
+/// and everything between them is kept untouched.
+///
+/// # Examples
+/// ```
+/// let nouns = ["box", "apple"];
+///
+/// pluralizer::pluralize_tokens("Put the box and the apple away.", 2, |w| nouns.contains(&w));
+/// // Put the boxes and the apples away.
+/// ```
+pub fn pluralize_tokens(text: &str, count: isize, predicate: impl Fn(&str) -> bool) -> String {
+    with_pluralizer(|p| p.pluralize_tokens(text, count, predicate))
+}
+
+/// Convert a word to its plural form.
+///
+/// Applies the same irregular, uncountable and regex rules as [pluralize](fn@pluralize) without
+/// requiring a count.
+///
+/// # Examples
+/// ```
+/// pluralizer::plural("House"); // Houses
+/// pluralizer::plural("Houses"); // Houses
+/// ```
+pub fn plural(word: &str) -> String {
+    to_plural(word)
+}
+
+/// Convert a word to its singular form.
+///
+/// Applies the same irregular, uncountable and regex rules as [pluralize](fn@pluralize) without
+/// requiring a count.
+///
+/// # Examples
+/// ```
+/// pluralizer::singular("Houses"); // House
+/// pluralizer::singular("House"); // House
+/// ```
+pub fn singular(word: &str) -> String {
+    to_singular(word)
+}
+
+/// Check that singularizing the plural of every word gives back the word.
+///
+/// Returns the words that aren't recovered, along with their plural and the singular of that
+/// plural, to catch plural and singular rules that aren't inverses of each other.
+///
+/// # Examples
+/// ```
+/// use regex::Regex;
+///
+/// pluralizer::add_plural_rule(Regex::new("(?i)orp$").unwrap(), "orpen".to_string()).unwrap();
+///
+/// let failures = pluralizer::verify_roundtrip(&["house", "blorp"]);
+/// // [RoundtripFailure { word: "blorp", plural: "blorpen", singular: "blorpen" }]
+/// ```
+pub fn verify_roundtrip(words: &[&str]) -> Vec<RoundtripFailure> {
+    with_pluralizer(|p| p.verify_roundtrip(words))
+}
+
+/// List every plural rule matching a word, with the pattern and the word the rule would produce.
+///
+/// Rules are listed from the one taking precedence, which is the one [plural](plural) applies, to
+/// the one tried last. Irregular and uncountable words are decided before the rules, so the first
+/// rule isn't applied to them; see [explain_plural](explain_plural) for the actual decision.
+///
+/// # Examples
+/// ```
+/// let rules = pluralizer::test_plural_rules("fox");
+/// // [("(?i)(x|ch|ss|sh|z)$", "foxes"), ("(?i)s?$", "foxs")]
+/// ```
+pub fn test_plural_rules(word: &str) -> Vec<(String, String)> {
+    with_pluralizer(|p| p.test_plural_rules(word))
+}
+
+/// List every singular rule matching a word, with the pattern and the word the rule would produce.
+///
+/// See [test_plural_rules](test_plural_rules).
+///
+/// # Examples
+/// ```
+/// let rules = pluralizer::test_singular_rules("foxes");
+/// // [("(?i)(x|ch|ss|sh|zz|...)(?:es)?$", "fox"), ("(?i)s$", "foxe")]
+/// ```
+pub fn test_singular_rules(word: &str) -> Vec<(String, String)> {
+    with_pluralizer(|p| p.test_singular_rules(word))
+}
+
+/// Convert a CamelCase type name to the snake_case plural used as its table name.
+///
+/// Only the last word is pluralized.
+///
+/// # Examples
+/// ```
+/// pluralizer::tableize("BlogPost"); // blog_posts
+/// pluralizer::tableize("Person"); // people
+/// pluralizer::tableize("HTTPRequest"); // http_requests
+/// ```
+pub fn tableize(type_name: &str) -> String {
+    with_pluralizer(|p| p.tableize(type_name))
+}
+
+/// Convert a snake_case table name to the CamelCase singular used as its type name.
+///
+/// This is the inverse of [tableize](tableize).
+///
+/// # Examples
+/// ```
+/// pluralizer::classify("blog_posts"); // BlogPost
+/// pluralizer::classify("people"); // Person
+/// ```
+pub fn classify(table_name: &str) -> String {
+    with_pluralizer(|p| p.classify(table_name))
+}
+
+/// Classify a word by the rules handling it.
+///
+/// Uncountable rules take precedence over irregular ones, words without any of them are reported
+/// as regular.
+///
+/// # Examples
+/// ```
+/// use pluralizer::WordClass;
+///
+/// pluralizer::classify_word("rice"); // WordClass::Uncountable
+/// pluralizer::classify_word("Geese"); // WordClass::Irregular
+/// pluralizer::classify_word("house"); // WordClass::Regular
+///
+/// pluralizer::add_invariant_rule("species");
+///
+/// let result = pluralizer::classify_word("species"); // WordClass::Invariant
+/// ```
+pub fn classify_word(word: &str) -> WordClass {
+    with_pluralizer(|p| p.classify_word(word))
+}
+
+/// Convert a word to its plural form and report which rule decided the result.
+///
+/// Useful to find out why a custom rule doesn't apply, e.g. because an irregular or uncountable
+/// rule takes precedence over it.
+///
+/// # Examples
+/// ```
+/// let goose = pluralizer::explain_plural("goose");
+/// // Transformation { result: "geese", source: TransformSource::Irregular }
+///
+/// let result = pluralizer::explain_plural("fox").source;
+/// // TransformSource::Rule { pattern: "(?i)(x|ch|ss|sh|z)$", placement: "$1es" }
+/// ```
+pub fn explain_plural(word: &str) -> Transformation {
+    with_pluralizer(|p| p.explain_plural(word))
+}
+
+/// Convert a word to its singular form and report which rule decided the result.
+///
+/// See [explain_plural](explain_plural).
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::explain_singular("sheep").source; // TransformSource::Uncountable
+/// ```
+pub fn explain_singular(word: &str) -> Transformation {
+    with_pluralizer(|p| p.explain_singular(word))
+}
+
+/// Convert a word to its plural form when `plural` is true and to its singular form otherwise.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_if("House", true); // Houses
+/// pluralizer::pluralize_if("Houses", false); // House
+/// ```
+pub fn pluralize_if(word: &str, plural: bool) -> String {
+    with_pluralizer(|p| p.pluralize_if(word, plural))
+}
+
+/// Write a word for an unknown count, with the plural suffix in parentheses.
+///
+/// When the plural is the singular followed by a suffix the suffix is parenthesized, e.g.
+/// "item(s)". Words whose plural changes the singular, like "city" or "person", are written as
+/// "singular/plural" instead, and uncountable words are returned unchanged.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_paren("item"); // item(s)
+/// pluralizer::pluralize_paren("boxes"); // box(es)
+/// pluralizer::pluralize_paren("person"); // person/people
+/// pluralizer::pluralize_paren("sheep"); // sheep
+/// ```
+pub fn pluralize_paren(word: &str) -> String {
+    with_pluralizer(|p| p.pluralize_paren(word))
+}
+
+/// Pluralize or singularize every word in a slice based on the passed in count.
+///
+/// The rules are locked once for the whole batch instead of once per word.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::pluralize_all(&["House", "child"], 2); // ["Houses", "children"]
+/// ```
+pub fn pluralize_all(words: &[&str], count: isize) -> Vec<String> {
+    with_pluralizer(|p| p.pluralize_all(words, count))
+}
+
+/// Convert every word in a slice to its plural form, locking the rules once.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::plural_all(&["user", "person"]); // ["users", "people"]
+/// ```
+pub fn plural_all(words: &[&str]) -> Vec<String> {
+    with_pluralizer(|p| p.plural_all(words))
+}
+
+/// Convert every word in a slice to its singular form, locking the rules once.
+///
+/// # Examples
+/// ```
+/// let result = pluralizer::singular_all(&["users", "people"]); // ["user", "person"]
+/// ```
+pub fn singular_all(words: &[&str]) -> Vec<String> {
+    with_pluralizer(|p| p.singular_all(words))
+}
+
+/// Check whether a word is already in its plural form.
+///
+/// Uncountable words are considered both plural and singular.
+///
+/// # Examples
+/// ```
+/// pluralizer::is_plural("Houses"); // true
+/// pluralizer::is_plural("House"); // false
+/// ```
+pub fn is_plural(word: &str) -> bool {
+    with_pluralizer(|p| p.is_plural(word))
+}
+
+/// Check whether a word is already in its singular form.
+///
+/// Uncountable words are considered both plural and singular.
+///
+/// # Examples
+/// ```
+/// pluralizer::is_singular("House"); // true
+/// pluralizer::is_singular("Houses"); // false
+/// ```
+pub fn is_singular(word: &str) -> bool {
+    with_pluralizer(|p| p.is_singular(word))
+}
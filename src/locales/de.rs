@@ -0,0 +1,88 @@
+//! German pluralization rules.
+//!
+//! A best-effort set covering the `-e`, `-en`, `-er` and `-s` endings, plus a table of common
+//! umlaut plurals like "Apfel" to "Äpfel". Nouns keep their capital letter.
+//!
+//! # Examples
+//! ```
+//! let german = pluralizer::locales::de::pluralizer();
+//!
+//! german.plural("Frau"); // Frauen
+//! german.plural("Kind"); // Kinder
+//! german.singular("Äpfel"); // Apfel
+//! ```
+
+use crate::constants;
+use crate::rules::RuleTables;
+use crate::Pluralizer;
+
+/// Irregular rules
+const IRREGULAR_RULES: &[(&str, &str)] = &[
+    ("apfel", "äpfel"),
+    ("baum", "bäume"),
+    ("bruder", "brüder"),
+    ("buch", "bücher"),
+    ("datum", "daten"),
+    ("fuß", "füße"),
+    ("hand", "hände"),
+    ("haus", "häuser"),
+    ("kind", "kinder"),
+    ("land", "länder"),
+    ("mann", "männer"),
+    ("museum", "museen"),
+    ("mutter", "mütter"),
+    ("nacht", "nächte"),
+    ("stadt", "städte"),
+    ("tochter", "töchter"),
+    ("vater", "väter"),
+    ("wort", "wörter"),
+];
+
+/// Pluralization rules
+const PLURAL_RULES: &[(&str, &str)] = &[
+    ("(?i)$", "e"),
+    ("(?i)e$", "en"),
+    // Endings borrowed from other languages, e.g. "Auto", "Oma".
+    ("(?i)([aiouy])$", "$1s"),
+    // Words ending in `-er`, `-el` and `-en` or a diminutive stay the same.
+    ("(?i)(er|el|en|chen|lein)$", "$1"),
+    ("(?i)(au|ei)$", "$1en"),
+    ("(?i)(ung|heit|keit|schaft|ion|tät|ik)$", "$1en"),
+    ("(?i)(erin|istin|entin|antin)$", "$1nen"),
+    ("(?i)(nis)$", "$1se"),
+];
+
+/// Singularization rules
+const SINGULAR_RULES: &[(&str, &str)] = &[
+    ("(?i)e$", ""),
+    ("(?i)en$", "e"),
+    ("(?i)([aiouy])s$", "$1"),
+    ("(?i)(chen|lein)$", "$1"),
+    ("(?i)(au|ei)en$", "$1"),
+    ("(?i)(ung|heit|keit|schaft|ion|tät|ik)en$", "$1"),
+    ("(?i)(erin|istin|entin|antin)nen$", "$1"),
+    ("(?i)(nis)se$", "$1"),
+];
+
+/// Uncountable rules
+const UNCOUNTABLE_RULES: &[&str] = &["fleisch", "milch", "obst", "polizei"];
+
+/// Prepositions that follow the head noun of a phrase, e.g. "Haus am See".
+const PREPOSITIONS: &[&str] = &[
+    "am", "an", "auf", "aus", "bei", "für", "im", "in", "mit", "nach", "ohne", "über", "unter",
+    "von", "vom", "zu", "zum", "zur",
+];
+
+/// Create an instance seeded with the German rules.
+pub fn pluralizer() -> Pluralizer {
+    Pluralizer::from_tables(&RuleTables {
+        irregular: IRREGULAR_RULES,
+        plural: PLURAL_RULES,
+        singular: SINGULAR_RULES,
+        uncountable: UNCOUNTABLE_RULES,
+        prepositions: PREPOSITIONS,
+        // Unit symbols are the same in every language.
+        unit_symbols: constants::UNIT_SYMBOLS,
+        ..RuleTables::default()
+    })
+}
@@ -0,0 +1,82 @@
+//! Spanish pluralization rules.
+//!
+//! Covers the regular vowel and consonant endings, the `z` to `c` change, stressed endings like
+//! "canción" and a few invariable words.
+//!
+//! # Examples
+//! ```
+//! let spanish = pluralizer::locales::es::pluralizer();
+//!
+//! spanish.plural("casa"); // casas
+//! spanish.plural("rey"); // reyes
+//! spanish.singular("peces"); // pez
+//! ```
+
+use crate::constants;
+use crate::rules::RuleTables;
+use crate::Pluralizer;
+
+/// Irregular rules
+const IRREGULAR_RULES: &[(&str, &str)] = &[
+    ("mes", "meses"),
+    ("país", "países"),
+    ("carácter", "caracteres"),
+    ("régimen", "regímenes"),
+    ("espécimen", "especímenes"),
+];
+
+/// Pluralization rules
+const PLURAL_RULES: &[(&str, &str)] = &[
+    // Words ending in a consonant, e.g. "papel".
+    ("(?i)$", "es"),
+    // Words ending in a vowel, e.g. "casa".
+    ("(?i)([aeiouáéíóú])$", "$1s"),
+    ("(?i)z$", "ces"),
+    // Stressed endings lose their accent, e.g. "canción".
+    ("(?i)ión$", "iones"),
+    ("(?i)ús$", "uses"),
+    ("(?i)és$", "eses"),
+];
+
+/// Singularization rules
+const SINGULAR_RULES: &[(&str, &str)] = &[
+    ("(?i)s$", ""),
+    ("(?i)([lnrdjy])es$", "$1"),
+    ("(?i)ces$", "z"),
+    ("(?i)iones$", "ión"),
+    ("(?i)uses$", "ús"),
+    ("(?i)eses$", "és"),
+];
+
+/// Uncountable rules
+const UNCOUNTABLE_RULES: &[&str] = &[
+    // Words ending in an unstressed `-s` or `-x` are invariable.
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "análisis",
+    "crisis",
+    "dosis",
+    "tesis",
+    "virus",
+    "tórax",
+];
+
+/// Prepositions that follow the head noun of a phrase, e.g. "casa de campo".
+const PREPOSITIONS: &[&str] = &["a", "con", "de", "del", "en", "para", "por", "sin", "sobre"];
+
+/// Create an instance seeded with the Spanish rules.
+pub fn pluralizer() -> Pluralizer {
+    Pluralizer::from_tables(&RuleTables {
+        irregular: IRREGULAR_RULES,
+        plural: PLURAL_RULES,
+        singular: SINGULAR_RULES,
+        uncountable: UNCOUNTABLE_RULES,
+        prepositions: PREPOSITIONS,
+        // Unit symbols are the same in every language.
+        unit_symbols: constants::UNIT_SYMBOLS,
+        ..RuleTables::default()
+    })
+}
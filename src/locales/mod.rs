@@ -0,0 +1,17 @@
+//! Rule sets for languages other than English.
+//!
+//! Every locale exposes a `pluralizer` function returning a [Pluralizer](crate::Pluralizer) seeded
+//! with its rules. The crate level functions keep using the English rules.
+//!
+//! # Examples
+//! ```
+//! use pluralizer::locales;
+//!
+//! let spanish = locales::es::pluralizer();
+//!
+//! spanish.pluralize("luz", 2, true); // 2 luces
+//! ```
+
+pub mod de;
+pub mod es;
+pub mod pt;
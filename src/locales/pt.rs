@@ -0,0 +1,96 @@
+//! Portuguese pluralization rules.
+//!
+//! Covers the regular vowel endings, the `-l` to `-is` and `-m` to `-ns` changes, and the `-ão`
+//! ending, which mostly becomes `-ões` with a table for the words taking `-ães` or `-ãos`.
+//!
+//! # Examples
+//! ```
+//! let portuguese = pluralizer::locales::pt::pluralizer();
+//!
+//! portuguese.plural("coração"); // corações
+//! portuguese.plural("pão"); // pães
+//! portuguese.singular("animais"); // animal
+//! portuguese.plural("fácil"); // fáceis
+//! ```
+
+use crate::constants;
+use crate::rules::RuleTables;
+use crate::Pluralizer;
+
+/// Irregular rules
+const IRREGULAR_RULES: &[(&str, &str)] = &[
+    // Words ending in `-ão` that don't take `-ões`.
+    ("alemão", "alemães"),
+    ("cão", "cães"),
+    ("capitão", "capitães"),
+    ("pão", "pães"),
+    ("cidadão", "cidadãos"),
+    ("cristão", "cristãos"),
+    ("irmão", "irmãos"),
+    ("mão", "mãos"),
+    ("órgão", "órgãos"),
+    // Words ending in a vowel whose plural looks like an `-l` plural.
+    ("pai", "pais"),
+    ("mês", "meses"),
+    ("país", "países"),
+];
+
+/// Pluralization rules
+const PLURAL_RULES: &[(&str, &str)] = &[
+    // Words ending in a vowel, e.g. "casa".
+    ("(?i)$", "s"),
+    ("(?i)([rz])$", "${1}es"),
+    ("(?i)m$", "ns"),
+    ("(?i)ão$", "ões"),
+    ("(?i)al$", "ais"),
+    ("(?i)el$", "éis"),
+    ("(?i)ol$", "óis"),
+    ("(?i)ul$", "uis"),
+    ("(?i)il$", "is"),
+    // Unstressed `-el` keeps its vowel, e.g. "nível".
+    ("(?i)([áéíóú][^aeiou]*)el$", "${1}eis"),
+    // Unstressed `-il` becomes `-eis`, e.g. "fácil".
+    ("(?i)([áéíóú][^aeiou]*)il$", "${1}eis"),
+];
+
+/// Singularization rules
+const SINGULAR_RULES: &[(&str, &str)] = &[
+    ("(?i)s$", ""),
+    ("(?i)([rz])es$", "$1"),
+    ("(?i)ns$", "m"),
+    ("(?i)[õã]es$", "ão"),
+    ("(?i)ais$", "al"),
+    ("(?i)éis$", "el"),
+    ("(?i)óis$", "ol"),
+    ("(?i)uis$", "ul"),
+    // Only after the consonants stressed `-il` usually follows, since "rubis" is "rubi".
+    ("(?i)(rr|[fntvz])is$", "${1}il"),
+    ("(?i)([áéíóú][^aeiou]*)eis$", "${1}el"),
+    // Unstressed `-il` after the consonants it usually follows, since "níveis" is "nível".
+    ("(?i)([áéíóú][^aeiou]*[cgst])eis$", "${1}il"),
+];
+
+/// Uncountable rules
+const UNCOUNTABLE_RULES: &[&str] = &[
+    // Words ending in an unstressed `-s` or `-x` are invariable.
+    "lápis", "ônibus", "vírus", "tórax", "tênis",
+];
+
+/// Prepositions that follow the head noun of a phrase, e.g. "casa de campo".
+const PREPOSITIONS: &[&str] = &[
+    "com", "da", "das", "de", "do", "dos", "em", "para", "por", "sem", "sobre",
+];
+
+/// Create an instance seeded with the Portuguese rules.
+pub fn pluralizer() -> Pluralizer {
+    Pluralizer::from_tables(&RuleTables {
+        irregular: IRREGULAR_RULES,
+        plural: PLURAL_RULES,
+        singular: SINGULAR_RULES,
+        uncountable: UNCOUNTABLE_RULES,
+        prepositions: PREPOSITIONS,
+        // Unit symbols are the same in every language.
+        unit_symbols: constants::UNIT_SYMBOLS,
+        ..RuleTables::default()
+    })
+}
@@ -0,0 +1,37 @@
+/// Pluralize or singularize a word based on the count, without including the count.
+///
+/// Expands to [pluralize_word](crate::pluralize_word), so a missing count fails to compile rather
+/// than being mistaken for the `include_count` flag.
+///
+/// # Examples
+/// ```
+/// use pluralizer::pluralize;
+///
+/// pluralize!("House", 2); // Houses
+/// pluralize!("Houses", 1); // House
+/// ```
+#[macro_export]
+macro_rules! pluralize {
+    ($word: expr, $count: expr $(,)?) => {
+        $crate::pluralize_word($word, $count)
+    };
+}
+
+/// Pluralize or singularize a word based on the count, prefixing it with the count.
+///
+/// Expands to [pluralize_with_count](crate::pluralize_with_count), with the count first as it's
+/// printed.
+///
+/// # Examples
+/// ```
+/// use pluralizer::count;
+///
+/// count!(2, "House"); // 2 Houses
+/// count!(1, "Houses"); // 1 House
+/// ```
+#[macro_export]
+macro_rules! count {
+    ($count: expr, $word: expr $(,)?) => {
+        $crate::pluralize_with_count($word, $count)
+    };
+}
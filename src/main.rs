@@ -0,0 +1,92 @@
+// Copyright 2022 pluralizer Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Command line interface to pluralize or singularize words from the shell.
+//!
+//! ```text
+//! pluralizer plural house        # houses
+//! pluralizer singular houses     # house
+//! pluralizer count 3 house       # 3 houses
+//! echo house | pluralizer plural # houses
+//! ```
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: pluralizer plural [WORD...]
+       pluralizer singular [WORD...]
+       pluralizer count <COUNT> [WORD...]
+
+Words are read from the standard input, one per line, when none is given.";
+
+/// Conversion applied to every word.
+type Convert = Box<dyn Fn(&str) -> String>;
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let (convert, words): (Convert, &[String]) = match args.split_first() {
+        Some((command, words)) if command == "plural" => (Box::new(pluralizer::plural), words),
+        Some((command, words)) if command == "singular" => (Box::new(pluralizer::singular), words),
+        Some((command, rest)) if command == "count" => match rest.split_first() {
+            Some((count, words)) => match count.parse::<isize>() {
+                Ok(count) => (
+                    Box::new(move |word| pluralizer::pluralize(word, count, true)),
+                    words,
+                ),
+                Err(_) => return usage_error(&format!("invalid count `{}`", count)),
+            },
+            None => return usage_error("missing count"),
+        },
+        Some((command, _)) => return usage_error(&format!("unknown command `{}`", command)),
+        None => return usage_error("missing command"),
+    };
+
+    let result = if words.is_empty() {
+        convert_lines(io::stdin().lock(), convert)
+    } else {
+        convert_words(words, convert)
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // The output was closed early, e.g. when piped into `head`.
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("pluralizer: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn convert_words(words: &[String], convert: impl Fn(&str) -> String) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    for word in words {
+        writeln!(stdout, "{}", convert(word))?;
+    }
+
+    Ok(())
+}
+
+fn convert_lines(input: impl BufRead, convert: impl Fn(&str) -> String) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    for line in input.lines() {
+        writeln!(stdout, "{}", convert(&line?))?;
+    }
+
+    Ok(())
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("pluralizer: {}\n\n{}", message, USAGE);
+
+    ExitCode::from(2)
+}
@@ -0,0 +1,58 @@
+use alloc::sync::Arc;
+use core::fmt;
+
+use crate::explain::Decision;
+
+/// Branch that produced the result of a word, reported to the
+/// [metrics hook](crate::set_metrics_hook).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Path {
+    /// The word is already in the wanted form according to an irregular rule.
+    Kept,
+    /// The word was replaced through an irregular rule.
+    Irregular,
+    /// The word was replaced through a classical plural in classical mode.
+    Classical,
+    /// A plural acronym dropped its suffix.
+    Acronym,
+    /// A regular expression rule matched the word.
+    Rule,
+    /// The word matched an uncountable word or regular expression.
+    Uncountable,
+    /// No rule matched, so the word was kept as is.
+    Unchanged,
+}
+
+impl From<Decision<'_>> for Path {
+    fn from(decision: Decision<'_>) -> Self {
+        match decision {
+            Decision::Kept => Path::Kept,
+            Decision::Irregular => Path::Irregular,
+            Decision::Classical => Path::Classical,
+            Decision::Acronym => Path::Acronym,
+            Decision::Rule(_) => Path::Rule,
+            Decision::Uncountable => Path::Uncountable,
+            Decision::Unchanged => Path::Unchanged,
+        }
+    }
+}
+
+/// Callback shared by the clones of a [Pluralizer](crate::Pluralizer).
+#[derive(Clone)]
+pub(crate) struct MetricsHook(Arc<dyn Fn(Path) + Send + Sync>);
+
+impl MetricsHook {
+    pub(crate) fn new(hook: impl Fn(Path) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    pub(crate) fn report(&self, path: Path) {
+        (self.0)(path)
+    }
+}
+
+impl fmt::Debug for MetricsHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsHook")
+    }
+}
@@ -0,0 +1,229 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Format a number as an English ordinal.
+///
+/// The teens always take `th`, so `11`, `12` and `13` become `11th`, `12th` and `13th`. Negative
+/// numbers keep their sign.
+///
+/// # Examples
+/// ```
+/// pluralizer::ordinalize(1); // 1st
+/// pluralizer::ordinalize(12); // 12th
+/// pluralizer::ordinalize(102); // 102nd
+/// ```
+pub fn ordinalize(n: isize) -> String {
+    format!("{}{}", n, ordinal_suffix(n.unsigned_abs()))
+}
+
+/// Prefix the singular form of a word with an ordinal.
+///
+/// # Examples
+/// ```
+/// pluralizer::ordinalize_word("house", 3); // 3rd house
+/// pluralizer::ordinalize_word("visitors", 21); // 21st visitor
+/// ```
+pub fn ordinalize_word(word: &str, n: isize) -> String {
+    format!("{} {}", ordinalize(n), crate::singular(word))
+}
+
+/// Prefix the pluralized word with the count spelled out in English words.
+///
+/// Compound numbers are hyphenated, zero is spelled `zero` and negative counts are prefixed with
+/// `minus`.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_words("house", 2); // two houses
+/// pluralizer::pluralize_words("cat", 21); // twenty-one cats
+/// pluralizer::pluralize_words("degree", -1); // minus one degree
+/// ```
+pub fn pluralize_words(word: &str, count: isize) -> String {
+    let words = number_to_words(count.unsigned_abs() as u128);
+    let pluralized = crate::pluralize(word, count, false);
+
+    if count < 0 {
+        format!("minus {} {}", words, pluralized)
+    } else {
+        format!("{} {}", words, pluralized)
+    }
+}
+
+/// Pluralize a word with its count, replacing a zero count with the given word.
+///
+/// A count of `0` still uses the plural form of the word. Every other count behaves exactly like
+/// [pluralize](fn@crate::pluralize) with the count included.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_with_zero("house", 0, "no"); // no houses
+/// pluralizer::pluralize_with_zero("house", 1, "no"); // 1 house
+/// ```
+pub fn pluralize_with_zero(word: &str, count: isize, zero_word: &str) -> String {
+    if count == 0 {
+        format!("{} {}", zero_word, crate::plural(word))
+    } else {
+        crate::pluralize(word, count, true)
+    }
+}
+
+/// Pluralize or singularize a word, grouping the thousands of the count with commas.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_grouped("house", 1000000, true); // 1,000,000 houses
+/// ```
+pub fn pluralize_grouped(word: &str, count: isize, include_count: bool) -> String {
+    pluralize_grouped_by(word, count, include_count, ',')
+}
+
+/// Pluralize or singularize a word, grouping the thousands of the count with the given separator.
+///
+/// # Examples
+/// ```
+/// pluralizer::pluralize_grouped_by("house", 1000000, true, '.'); // 1.000.000 houses
+/// ```
+pub fn pluralize_grouped_by(
+    word: &str,
+    count: isize,
+    include_count: bool,
+    separator: char,
+) -> String {
+    crate::with_pluralizer(|p| {
+        let pluralized = p.pluralize_word(word, count);
+
+        if include_count {
+            format!(
+                "{} {}",
+                p.format_count(group_digits(count, separator)),
+                pluralized
+            )
+        } else {
+            pluralized
+        }
+    })
+}
+
+pub(crate) fn group_digits(count: isize, separator: char) -> String {
+    let digits = count.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() * 4 / 3 + 1);
+
+    if count < 0 {
+        grouped.push('-');
+    }
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+const ONES: &[&str] = &[
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: &[&str] = &[
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: &[&str] = &[
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+pub(crate) fn number_to_words(n: u128) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = n;
+    let mut scale = 0;
+
+    while rest > 0 {
+        let group = (rest % 1000) as usize;
+
+        if group > 0 {
+            let words = below_thousand(group);
+
+            groups.push(if SCALES[scale].is_empty() {
+                words
+            } else {
+                format!("{} {}", words, SCALES[scale])
+            });
+        }
+
+        rest /= 1000;
+        scale += 1;
+    }
+
+    groups.reverse();
+    groups.join(" ")
+}
+
+fn below_thousand(n: usize) -> String {
+    let hundreds = n / 100;
+    let rest = n % 100;
+
+    let rest_words = match rest {
+        0 => String::new(),
+        1..=19 => ONES[rest].to_string(),
+        _ if rest.is_multiple_of(10) => TENS[rest / 10].to_string(),
+        _ => format!("{}-{}", TENS[rest / 10], ONES[rest % 10]),
+    };
+
+    match (hundreds, rest) {
+        (0, _) => rest_words,
+        (_, 0) => format!("{} hundred", ONES[hundreds]),
+        _ => format!("{} hundred {}", ONES[hundreds], rest_words),
+    }
+}
+
+fn ordinal_suffix(n: usize) -> &'static str {
+    if (11..=13).contains(&(n % 100)) {
+        return "th";
+    }
+
+    match n % 10 {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    }
+}
@@ -0,0 +1,1952 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use regex::Regex;
+
+#[cfg(feature = "cache")]
+use crate::cache::{self, ResultCache};
+use crate::config::{RegexRule, RuleConfig};
+use crate::constants;
+use crate::explain::{Decision, RoundtripFailure, Transformation};
+use crate::inflections::{self, Inflection};
+#[cfg(feature = "json")]
+use crate::json::{self, LoadError};
+#[cfg(feature = "metrics")]
+use crate::metrics::{MetricsHook, Path};
+use crate::rules::{Priority, RuleList, RuleTables, WordRule};
+use crate::Article;
+use crate::CaseMode;
+use crate::Count;
+use crate::Map;
+use crate::PluralizerBuilder;
+use crate::RuleError;
+use crate::UncountableRule;
+use crate::WordClass;
+
+/// Direction of a word transformation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Form {
+    Singular,
+    Plural,
+}
+
+macro_rules! load_regex_vec {
+    ($rules: expr, $uncountable: expr) => {{
+        let mut vec = $rules
+            .iter()
+            .map(|(k, v)| WordRule {
+                rule: Regex::new(k).expect("Invalid regular expression"),
+                placement: v.to_string(),
+            })
+            .collect::<Vec<WordRule>>();
+
+        vec.append(
+            &mut $uncountable
+                .iter()
+                .map(|s| WordRule {
+                    rule: Regex::new(s).expect("Invalid regular expression"),
+                    placement: "$0".to_string(),
+                })
+                .collect(),
+        );
+
+        vec
+    }};
+}
+
+macro_rules! load_irregular_map {
+    ($rules: expr, $map: expr) => {
+        $rules.iter().map($map).collect()
+    };
+}
+
+/// A self-contained set of pluralization rules.
+///
+/// Every instance owns its own irregular, plural, singular and uncountable rules, so rules added
+/// to one instance never leak into another. The crate level functions like
+/// [pluralize](fn@crate::pluralize) delegate to a shared default instance.
+///
+/// Instances are `Send` and `Sync`, so one can be shared between threads behind an `Arc` once its
+/// rules are set up. Reads never block each other, except that with the `cache` feature each read
+/// briefly locks the cache of the instance.
+///
+/// # Examples
+/// ```
+/// use pluralizer::Pluralizer;
+///
+/// let mut pluralizer = Pluralizer::new();
+///
+/// pluralizer.add_irregular_rule("cow".to_string(), "kine".to_string());
+///
+/// let result = pluralizer.pluralize("cow", 2, false); // kine
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pluralizer {
+    irregular_singles: Map<String, String>,
+    irregular_plurals: Map<String, String>,
+    classical_singles: Map<String, String>,
+    classical_plurals: Map<String, String>,
+    plural_rules: RuleList,
+    singular_rules: RuleList,
+    uncountable_rules: Vec<String>,
+    exact_uncountable_rules: Vec<String>,
+    uncountable_regexes: Vec<Regex>,
+    prepositions: Vec<String>,
+    postpositives: Vec<String>,
+    s_exceptions: Vec<String>,
+    contractions: Vec<String>,
+    unit_symbols: Vec<String>,
+    acronym_mode: bool,
+    strict_mode: bool,
+    singular_predicate: fn(isize) -> bool,
+    max_rules: Option<usize>,
+    max_word_len: Option<usize>,
+    bidi_isolation: bool,
+    articles: Map<String, Article>,
+    collectives: Map<String, String>,
+    tables: RuleTables,
+    #[cfg(feature = "cache")]
+    cache: ResultCache,
+    #[cfg(feature = "metrics")]
+    metrics_hook: Option<MetricsHook>,
+}
+
+impl Default for Pluralizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pluralizer {
+    /// Create a new instance seeded with the default rules.
+    ///
+    /// When the `default-rules` feature is disabled the instance starts [empty](Pluralizer::empty)
+    /// instead.
+    pub fn new() -> Self {
+        #[cfg(feature = "default-rules")]
+        return Self::with_default_rules();
+
+        #[cfg(not(feature = "default-rules"))]
+        return Self::empty();
+    }
+
+    /// Create a new instance without any rules, words are returned unchanged until rules are added.
+    pub fn empty() -> Self {
+        Self {
+            irregular_singles: Map::new(),
+            irregular_plurals: Map::new(),
+            classical_singles: Map::new(),
+            classical_plurals: Map::new(),
+            plural_rules: RuleList::default(),
+            singular_rules: RuleList::default(),
+            uncountable_rules: Vec::new(),
+            exact_uncountable_rules: Vec::new(),
+            uncountable_regexes: Vec::new(),
+            prepositions: Vec::new(),
+            postpositives: Vec::new(),
+            s_exceptions: Vec::new(),
+            contractions: Vec::new(),
+            unit_symbols: Vec::new(),
+            acronym_mode: true,
+            strict_mode: false,
+            singular_predicate: is_singular_count,
+            max_rules: None,
+            max_word_len: None,
+            bidi_isolation: false,
+            articles: Map::new(),
+            collectives: Map::new(),
+            tables: RuleTables::default(),
+            #[cfg(feature = "cache")]
+            cache: ResultCache::new(cache::DEFAULT_CAPACITY),
+            #[cfg(feature = "metrics")]
+            metrics_hook: None,
+        }
+    }
+
+    /// Configure a new instance by chaining rules, see [PluralizerBuilder](PluralizerBuilder).
+    pub fn builder() -> PluralizerBuilder {
+        PluralizerBuilder::default()
+    }
+
+    #[cfg_attr(not(feature = "default-rules"), allow(dead_code))]
+    fn with_default_rules() -> Self {
+        Self::from_tables(&RuleTables {
+            irregular: constants::IRREGULAR_RULES,
+            plural: constants::PLURAL_RULES,
+            singular: constants::SINGULAR_RULES,
+            uncountable: constants::UNCOUNTABLE_RULES,
+            uncountable_regex: constants::UNCOUNTABLE_REGEX_RULES,
+            prepositions: constants::PREPOSITIONS,
+            postpositives: constants::POSTPOSITIVES,
+            o_exceptions: constants::O_EXCEPTIONS,
+            f_exceptions: constants::F_EXCEPTIONS,
+            contractions: constants::CONTRACTIONS,
+            collectives: constants::COLLECTIVES,
+            unit_symbols: constants::UNIT_SYMBOLS,
+        })
+    }
+
+    /// Create a new instance seeded from the given rule tables.
+    pub(crate) fn from_tables(tables: &RuleTables) -> Self {
+        let mut pluralizer = Self {
+            plural_rules: RuleList::from(load_regex_vec!(tables.plural, tables.uncountable_regex)),
+            singular_rules: RuleList::from(load_regex_vec!(
+                tables.singular,
+                tables.uncountable_regex
+            )),
+            uncountable_regexes: tables
+                .uncountable_regex
+                .iter()
+                .map(|rule| Regex::new(rule).expect("Invalid regular expression"))
+                .collect(),
+            tables: *tables,
+            ..Self::empty()
+        };
+
+        pluralizer.seed_words();
+        pluralizer
+    }
+
+    /// Fill the word collections from the rule tables, dropping the words added since.
+    ///
+    /// Unlike the regular expression rules, these are cheap to rebuild.
+    fn seed_words(&mut self) {
+        let tables = self.tables;
+        let words = |table: &[&str]| table.iter().map(|s| s.to_string()).collect();
+
+        self.irregular_singles =
+            load_irregular_map!(tables.irregular, |(k, v)| (k.to_string(), v.to_string()));
+        self.irregular_plurals =
+            load_irregular_map!(tables.irregular, |(k, v)| (v.to_string(), k.to_string()));
+        self.uncountable_rules = words(tables.uncountable);
+        self.exact_uncountable_rules.clear();
+        self.prepositions = words(tables.prepositions);
+        self.postpositives = words(tables.postpositives);
+        self.s_exceptions = tables
+            .o_exceptions
+            .iter()
+            .chain(tables.f_exceptions)
+            .map(|s| s.to_string())
+            .collect();
+        self.contractions = words(tables.contractions);
+        self.unit_symbols = words(tables.unit_symbols);
+        self.articles.clear();
+        self.collectives = tables
+            .collectives
+            .iter()
+            .map(|(word, collective)| (word.to_string(), collective.to_string()))
+            .collect();
+    }
+
+    /// Remove every rule added since the instance was created, keeping the rules it was seeded
+    /// with.
+    ///
+    /// See [clear_user_rules](crate::clear_user_rules).
+    pub fn clear_user_rules(&mut self) {
+        self.rules_changed();
+        self.plural_rules.clear_added();
+        self.singular_rules.clear_added();
+        self.uncountable_regexes
+            .truncate(self.tables.uncountable_regex.len());
+        self.seed_words();
+    }
+
+    /// Add an irregular word definition.
+    ///
+    /// See [add_irregular_rule](crate::add_irregular_rule).
+    pub fn add_irregular_rule(&mut self, singular: String, plural: String) {
+        self.rules_changed();
+        self.irregular_singles
+            .insert(singular.to_string(), plural.to_string());
+        self.irregular_plurals.insert(plural, singular);
+    }
+
+    /// Add a word whose singular and plural forms are identical.
+    ///
+    /// See [add_invariant_rule](crate::add_invariant_rule).
+    pub fn add_invariant_rule(&mut self, word: &str) {
+        let word = word.to_lowercase();
+
+        self.add_irregular_rule(word.clone(), word);
+    }
+
+    /// Prefix a singular word with "a" or "an".
+    ///
+    /// See [with_article](crate::with_article).
+    pub fn with_article(&self, word: &str) -> String {
+        if word.trim().is_empty() {
+            return word.to_string();
+        }
+
+        let article = match self.articles.get(&word.to_lowercase()) {
+            Some(article) => *article,
+            None => Article::guess(word),
+        };
+
+        format!("{} {}", article, word)
+    }
+
+    /// Set the article used before a word.
+    ///
+    /// See [add_article_rule](crate::add_article_rule).
+    pub fn add_article_rule(&mut self, word: &str, article: Article) {
+        self.articles.insert(word.to_lowercase(), article);
+    }
+
+    /// Look up the collective noun for a group of the word.
+    ///
+    /// See [collective](crate::collective).
+    pub fn collective(&self, word: &str) -> Option<String> {
+        self.collectives
+            .get(&self.singular(word).to_lowercase())
+            .cloned()
+    }
+
+    /// Set the collective noun for a group of the word.
+    ///
+    /// See [add_collective](crate::add_collective).
+    pub fn add_collective(&mut self, word: &str, collective: &str) {
+        self.collectives
+            .insert(self.singular(word).to_lowercase(), collective.to_string());
+    }
+
+    /// Name a group of the word with its collective noun, counting the groups.
+    ///
+    /// See [pluralize_collective](crate::pluralize_collective).
+    pub fn pluralize_collective(&self, word: &str, count: isize) -> Option<String> {
+        let collective = self.collective(word)?;
+        let groups = if self.takes_singular(count) {
+            self.with_article(&collective)
+        } else {
+            self.with_count(&collective, count, " ")
+        };
+
+        Some(format!("{} of {}", groups, self.plural(word)))
+    }
+
+    /// Add several irregular word definitions.
+    ///
+    /// See [add_irregular_rules](crate::add_irregular_rules).
+    pub fn add_irregular_rules<I: IntoIterator<Item = (String, String)>>(&mut self, pairs: I) {
+        for (singular, plural) in pairs {
+            self.add_irregular_rule(singular, plural);
+        }
+    }
+
+    /// Remove an irregular word definition by its singular form.
+    ///
+    /// See [remove_irregular_rule](crate::remove_irregular_rule).
+    pub fn remove_irregular_rule(&mut self, singular: &str) -> bool {
+        self.rules_changed();
+
+        let removed = self.irregular_singles.remove(singular);
+
+        if let Some(plural) = &removed {
+            self.irregular_plurals.remove(plural);
+        }
+
+        // Drop any other reverse entry still pointing at the singular.
+        let plurals = self.irregular_plurals.len();
+        self.irregular_plurals.retain(|_, s| s != singular);
+
+        removed.is_some() || plurals != self.irregular_plurals.len()
+    }
+
+    /// Look up the irregular plural registered for a singular word.
+    ///
+    /// See [get_irregular_plural](crate::get_irregular_plural).
+    pub fn get_irregular_plural(&self, singular: &str) -> Option<String> {
+        self.irregular_singles
+            .get(&singular.to_lowercase())
+            .cloned()
+    }
+
+    /// Look up the irregular singular registered for a plural word.
+    ///
+    /// See [get_irregular_singular](crate::get_irregular_singular).
+    pub fn get_irregular_singular(&self, plural: &str) -> Option<String> {
+        self.irregular_plurals.get(&plural.to_lowercase()).cloned()
+    }
+
+    /// Snapshot every irregular `(singular, plural)` pair.
+    ///
+    /// See [irregular_rules](crate::irregular_rules).
+    pub fn irregular_rules(&self) -> Vec<(String, String)> {
+        let mut rules: Vec<_> = self
+            .irregular_singles
+            .iter()
+            .map(|(singular, plural)| (singular.clone(), plural.clone()))
+            .collect();
+        rules.sort();
+
+        rules
+    }
+
+    /// Add a pluralization rule to the collection.
+    ///
+    /// See [add_plural_rule](crate::add_plural_rule).
+    pub fn add_plural_rule(&mut self, rule: Regex, placement: String) -> Result<(), RuleError> {
+        self.add_rule(Form::Plural, rule, placement, Priority::Last)
+    }
+
+    /// Add a pluralization rule, choosing whether it takes precedence over the existing rules.
+    ///
+    /// See [add_plural_rule_with_priority](crate::add_plural_rule_with_priority).
+    pub fn add_plural_rule_with_priority(
+        &mut self,
+        rule: Regex,
+        placement: String,
+        priority: Priority,
+    ) -> Result<(), RuleError> {
+        self.add_rule(Form::Plural, rule, placement, priority)
+    }
+
+    fn add_rule(
+        &mut self,
+        form: Form,
+        rule: Regex,
+        placement: String,
+        priority: Priority,
+    ) -> Result<(), RuleError> {
+        validate_placement(&rule, &placement)?;
+
+        let rule = WordRule { rule, placement };
+        let rules = match form {
+            Form::Singular => &self.singular_rules,
+            Form::Plural => &self.plural_rules,
+        };
+
+        // Adding the same rule again is a no-op, so it never counts against the limit.
+        if rules.contains(&rule) {
+            return Ok(());
+        }
+
+        self.check_rule_room(1)?;
+        self.rules_changed();
+
+        let rules = match form {
+            Form::Singular => &mut self.singular_rules,
+            Form::Plural => &mut self.plural_rules,
+        };
+
+        match priority {
+            Priority::First => rules.insert_first(rule),
+            Priority::Last => rules.push(rule),
+        }
+
+        Ok(())
+    }
+
+    /// Compile and add a pluralization rule to the collection.
+    ///
+    /// See [try_add_plural_rule](crate::try_add_plural_rule).
+    pub fn try_add_plural_rule(
+        &mut self,
+        pattern: &str,
+        placement: String,
+    ) -> Result<(), RuleError> {
+        self.add_plural_rule(Regex::new(pattern)?, placement)
+    }
+
+    /// Add a pluralization rule, rejecting patterns not anchored at the end of the word.
+    ///
+    /// See [add_plural_rule_checked](crate::add_plural_rule_checked).
+    pub fn add_plural_rule_checked(
+        &mut self,
+        rule: Regex,
+        placement: String,
+    ) -> Result<(), RuleError> {
+        validate_anchor(&rule)?;
+        self.add_plural_rule(rule, placement)
+    }
+
+    /// Add a singularization rule to the collection.
+    ///
+    /// See [add_singular_rule](crate::add_singular_rule).
+    pub fn add_singular_rule(&mut self, rule: Regex, placement: String) -> Result<(), RuleError> {
+        self.add_rule(Form::Singular, rule, placement, Priority::Last)
+    }
+
+    /// Add a singularization rule, choosing whether it takes precedence over the existing rules.
+    ///
+    /// See [add_singular_rule_with_priority](crate::add_singular_rule_with_priority).
+    pub fn add_singular_rule_with_priority(
+        &mut self,
+        rule: Regex,
+        placement: String,
+        priority: Priority,
+    ) -> Result<(), RuleError> {
+        self.add_rule(Form::Singular, rule, placement, priority)
+    }
+
+    /// Compile and add a singularization rule to the collection.
+    ///
+    /// See [try_add_singular_rule](crate::try_add_singular_rule).
+    pub fn try_add_singular_rule(
+        &mut self,
+        pattern: &str,
+        placement: String,
+    ) -> Result<(), RuleError> {
+        self.add_singular_rule(Regex::new(pattern)?, placement)
+    }
+
+    /// Add a singularization rule, rejecting patterns not anchored at the end of the word.
+    ///
+    /// See [add_singular_rule_checked](crate::add_singular_rule_checked).
+    pub fn add_singular_rule_checked(
+        &mut self,
+        rule: Regex,
+        placement: String,
+    ) -> Result<(), RuleError> {
+        validate_anchor(&rule)?;
+        self.add_singular_rule(rule, placement)
+    }
+
+    /// Add an uncountable word rule.
+    ///
+    /// See [add_uncountable_rule](crate::add_uncountable_rule).
+    pub fn add_uncountable_rule(&mut self, rule: UncountableRule) {
+        self.rules_changed();
+
+        match rule {
+            UncountableRule::Regex(rule) => {
+                if self
+                    .uncountable_regexes
+                    .iter()
+                    .any(|r| r.as_str() == rule.as_str())
+                {
+                    return;
+                }
+
+                // We add it as both plural and singular rules with same placement
+                self.plural_rules.push(WordRule {
+                    rule: rule.clone(),
+                    placement: "$0".to_string(),
+                });
+                self.singular_rules.push(WordRule {
+                    rule: rule.clone(),
+                    placement: "$0".to_string(),
+                });
+                self.uncountable_regexes.push(rule);
+            }
+            UncountableRule::String(rule) => {
+                let rule = rule.to_lowercase();
+
+                if !self.uncountable_rules.contains(&rule) {
+                    self.uncountable_rules.push(rule);
+                }
+            }
+            UncountableRule::StringExact(rule) => {
+                if !self.exact_uncountable_rules.contains(&rule) {
+                    self.exact_uncountable_rules.push(rule);
+                }
+            }
+        }
+    }
+
+    /// Check if exactly this uncountable rule was added, as opposed to a word matching one.
+    pub(crate) fn has_uncountable_rule(&self, rule: &UncountableRule) -> bool {
+        match rule {
+            UncountableRule::Regex(rule) => self
+                .uncountable_regexes
+                .iter()
+                .any(|r| r.as_str() == rule.as_str()),
+            UncountableRule::String(rule) => self.uncountable_rules.contains(&rule.to_lowercase()),
+            UncountableRule::StringExact(rule) => self.exact_uncountable_rules.contains(rule),
+        }
+    }
+
+    /// Remove an uncountable rule added with [add_uncountable_rule](Self::add_uncountable_rule).
+    pub(crate) fn remove_uncountable_rule(&mut self, rule: &UncountableRule) {
+        self.rules_changed();
+
+        match rule {
+            UncountableRule::Regex(rule) => {
+                let word_rule = WordRule {
+                    rule: rule.clone(),
+                    placement: "$0".to_string(),
+                };
+
+                self.plural_rules.remove(&word_rule);
+                self.singular_rules.remove(&word_rule);
+                self.uncountable_regexes
+                    .retain(|r| r.as_str() != rule.as_str());
+            }
+            UncountableRule::String(rule) => {
+                let rule = rule.to_lowercase();
+
+                self.uncountable_rules.retain(|r| *r != rule);
+            }
+            UncountableRule::StringExact(rule) => {
+                self.exact_uncountable_rules.retain(|r| r != rule);
+            }
+        }
+    }
+
+    /// Add several uncountable words.
+    ///
+    /// See [add_uncountable_rules](crate::add_uncountable_rules).
+    pub fn add_uncountable_rules<I: IntoIterator<Item = String>>(&mut self, words: I) {
+        for word in words {
+            self.add_uncountable_rule(UncountableRule::String(word));
+        }
+    }
+
+    /// Compile and add an uncountable regex rule.
+    ///
+    /// See [try_add_uncountable_rule](crate::try_add_uncountable_rule).
+    pub fn try_add_uncountable_rule(&mut self, pattern: &str) -> Result<(), RuleError> {
+        self.add_uncountable_rule(UncountableRule::Regex(Regex::new(pattern)?));
+
+        Ok(())
+    }
+
+    /// Snapshot the uncountable words.
+    ///
+    /// See [uncountable_words](crate::uncountable_words).
+    pub fn uncountable_words(&self) -> Vec<String> {
+        self.uncountable_rules.clone()
+    }
+
+    /// Check if a word matches one of the uncountable rules.
+    ///
+    /// See [is_uncountable](crate::is_uncountable).
+    pub fn is_uncountable(&self, word: &str) -> bool {
+        self.uncountable_rules.contains(&word.to_lowercase())
+            || self.exact_uncountable_rules.iter().any(|w| w == word)
+            || self
+                .uncountable_regexes
+                .iter()
+                .any(|rule| rule.is_match(word))
+    }
+
+    /// Add every rule of a configuration.
+    ///
+    /// See [load_config](crate::load_config).
+    pub fn load_config(&mut self, config: &RuleConfig) -> Result<(), RuleError> {
+        self.load_config_rules(config).map_err(|(_, error)| error)
+    }
+
+    /// Add every rule of a configuration, returning the offending pattern on failure.
+    pub(crate) fn load_config_rules<'c>(
+        &mut self,
+        config: &'c RuleConfig,
+    ) -> Result<(), (&'c str, RuleError)> {
+        let plural = compile_rules(&config.plural)?;
+        let singular = compile_rules(&config.singular)?;
+
+        if let Err(error) = self.check_rule_room(plural.len() + singular.len()) {
+            // Report the first rule that doesn't fit.
+            let room = self.max_rules.unwrap_or_default() - self.rule_count();
+            let rule = config.plural.iter().chain(&config.singular).nth(room);
+
+            return Err((rule.map_or("", |rule| rule.pattern.as_str()), error));
+        }
+
+        let uncountable = config
+            .uncountable_regex
+            .iter()
+            .map(|pattern| Regex::new(pattern).map_err(|error| (pattern.as_str(), error.into())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.rules_changed();
+
+        for (singular, plural) in &config.irregular {
+            self.add_irregular_rule(singular.clone(), plural.clone());
+        }
+
+        for rule in plural {
+            self.plural_rules.push(rule);
+        }
+
+        for rule in singular {
+            self.singular_rules.push(rule);
+        }
+
+        for word in &config.uncountable {
+            self.add_uncountable_rule(UncountableRule::String(word.clone()));
+        }
+
+        for word in &config.uncountable_exact {
+            self.add_uncountable_rule(UncountableRule::StringExact(word.clone()));
+        }
+
+        for rule in uncountable {
+            self.add_uncountable_rule(UncountableRule::Regex(rule));
+        }
+
+        Ok(())
+    }
+
+    /// Load a configuration from a JSON file and add its rules.
+    ///
+    /// See [load_rules_from_json](crate::load_rules_from_json).
+    #[cfg(feature = "json")]
+    pub fn load_rules_from_json(&mut self, path: &std::path::Path) -> Result<(), LoadError> {
+        let (json, config) = json::read_config(path)?;
+
+        self.load_config_rules(&config)
+            .map_err(|(pattern, error)| json::invalid_rule(&json, pattern, error))
+    }
+
+    /// Snapshot the current rules as a configuration.
+    ///
+    /// See [export_config](crate::export_config).
+    pub fn export_config(&self) -> RuleConfig {
+        let export_rules = |rules: &RuleList| {
+            rules
+                .iter()
+                .filter(|rule| !self.is_uncountable_rule(rule))
+                .map(|rule| RegexRule {
+                    pattern: rule.rule.as_str().to_string(),
+                    placement: rule.placement.clone(),
+                })
+                .collect()
+        };
+
+        RuleConfig {
+            irregular: self.irregular_rules(),
+            plural: export_rules(&self.plural_rules),
+            singular: export_rules(&self.singular_rules),
+            uncountable: self.uncountable_rules.clone(),
+            uncountable_exact: self.exact_uncountable_rules.clone(),
+            uncountable_regex: self
+                .uncountable_regexes
+                .iter()
+                .map(|rule| rule.as_str().to_string())
+                .collect(),
+        }
+    }
+
+    /// Add a word ending in `o` that only takes an `s` in the plural.
+    ///
+    /// See [add_o_exception](crate::add_o_exception).
+    pub fn add_o_exception(&mut self, word: &str) {
+        let word = word.to_lowercase();
+
+        if !self.s_exceptions.contains(&word) {
+            self.rules_changed();
+            self.s_exceptions.push(word);
+        }
+    }
+
+    /// Add a contraction that's never pluralized or singularized.
+    ///
+    /// See [add_contraction](crate::add_contraction).
+    pub fn add_contraction(&mut self, word: &str) {
+        let word = normalize_contraction(word);
+
+        if !self.contractions.contains(&word) {
+            self.rules_changed();
+            self.contractions.push(word);
+        }
+    }
+
+    /// Add a preposition that marks the end of the head noun in a phrase.
+    ///
+    /// See [add_preposition](crate::add_preposition).
+    pub fn add_preposition(&mut self, preposition: String) {
+        self.rules_changed();
+        self.prepositions.push(preposition.to_lowercase());
+    }
+
+    /// Add a word that is placed after the head noun in a phrase.
+    ///
+    /// See [add_postpositive](crate::add_postpositive).
+    pub fn add_postpositive(&mut self, postpositive: String) {
+        self.rules_changed();
+        self.postpositives.push(postpositive.to_lowercase());
+    }
+
+    /// Prefer classical Latin and Greek plurals over the anglicized ones.
+    ///
+    /// See [set_classical_mode](crate::set_classical_mode).
+    pub fn set_classical_mode(&mut self, enabled: bool) {
+        self.rules_changed();
+
+        if enabled {
+            self.classical_singles = load_irregular_map!(
+                constants::CLASSICAL_IRREGULAR_RULES,
+                |(k, v)| (k.to_string(), v.to_string())
+            );
+            self.classical_plurals = load_irregular_map!(
+                constants::CLASSICAL_IRREGULAR_RULES,
+                |(k, v)| (v.to_string(), k.to_string())
+            );
+        } else {
+            self.classical_singles.clear();
+            self.classical_plurals.clear();
+        }
+    }
+
+    /// Classify a word by the rules handling it.
+    ///
+    /// See [classify_word](crate::classify_word).
+    pub fn classify_word(&self, word: &str) -> WordClass {
+        if self.is_uncountable(word) {
+            return WordClass::Uncountable;
+        }
+
+        let token = word.to_lowercase();
+        let irregular = [
+            &self.irregular_singles,
+            &self.irregular_plurals,
+            &self.classical_singles,
+            &self.classical_plurals,
+        ]
+        .into_iter()
+        .find_map(|map| map.get(&token));
+
+        match irregular {
+            Some(other) if *other == token => WordClass::Invariant,
+            Some(_) => WordClass::Irregular,
+            None if self.is_contraction(word) => WordClass::Invariant,
+            None => WordClass::Regular,
+        }
+    }
+
+    /// Count the plural and singular rules.
+    ///
+    /// See [rule_count](crate::rule_count).
+    pub fn rule_count(&self) -> usize {
+        self.plural_rules.len() + self.singular_rules.len()
+    }
+
+    /// Limit the number of plural and singular rules, `None` removes the limit.
+    ///
+    /// See [set_max_rules](crate::set_max_rules).
+    pub fn set_max_rules(&mut self, max: Option<usize>) {
+        self.max_rules = max;
+    }
+
+    /// Limit the length in bytes of the words to replace, `None` removes the limit.
+    ///
+    /// See [set_max_word_len](crate::set_max_word_len).
+    pub fn set_max_word_len(&mut self, max: Option<usize>) {
+        self.max_word_len = max;
+    }
+
+    fn check_rule_room(&self, rules: usize) -> Result<(), RuleError> {
+        match self.max_rules {
+            Some(max) if self.rule_count() + rules > max => Err(RuleError::TooManyRules { max }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Call a hook with the branch that produced each converted word.
+    ///
+    /// See [set_metrics_hook](crate::set_metrics_hook).
+    #[cfg(feature = "metrics")]
+    pub fn set_metrics_hook(&mut self, hook: impl Fn(Path) + Send + Sync + 'static) {
+        self.metrics_hook = Some(MetricsHook::new(hook));
+    }
+
+    /// Wrap the counts in Unicode bidi isolates.
+    ///
+    /// See [set_bidi_isolation](crate::set_bidi_isolation).
+    pub fn set_bidi_isolation(&mut self, enabled: bool) {
+        self.bidi_isolation = enabled;
+    }
+
+    /// Decide which counts take the singular form.
+    ///
+    /// See [set_singular_predicate](crate::set_singular_predicate).
+    pub fn set_singular_predicate(&mut self, predicate: fn(isize) -> bool) {
+        self.singular_predicate = predicate;
+    }
+
+    pub(crate) fn takes_singular(&self, count: isize) -> bool {
+        (self.singular_predicate)(count)
+    }
+
+    /// Keep short all caps words like "URL" upper cased while adding a lower cased suffix.
+    ///
+    /// See [set_acronym_mode](crate::set_acronym_mode).
+    pub fn set_acronym_mode(&mut self, enabled: bool) {
+        self.rules_changed();
+        self.acronym_mode = enabled;
+    }
+
+    /// Only convert words with an irregular rule, keeping every other word unchanged.
+    ///
+    /// See [set_strict_mode](crate::set_strict_mode).
+    pub fn set_strict_mode(&mut self, enabled: bool) {
+        self.rules_changed();
+        self.strict_mode = enabled;
+    }
+
+    /// Set how many transformed words are remembered, `0` disables the cache.
+    ///
+    /// See [set_cache_capacity](crate::set_cache_capacity).
+    #[cfg(feature = "cache")]
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        self.cache.set_capacity(capacity);
+    }
+
+    /// Forget cached results after the rules change.
+    fn rules_changed(&mut self) {
+        #[cfg(feature = "cache")]
+        self.cache.clear();
+    }
+
+    /// Pluralize or singularize a word based on the passed in count.
+    ///
+    /// See [pluralize](fn@crate::pluralize).
+    pub fn pluralize(&self, word: &str, count: impl Count, include_count: bool) -> String {
+        if include_count {
+            self.pluralize_with_count(word, count)
+        } else {
+            self.pluralize_word(word, count)
+        }
+    }
+
+    /// Pluralize or singularize a word based on the count, including the count.
+    ///
+    /// See [pluralize_with_count](crate::pluralize_with_count).
+    pub fn pluralize_with_count(&self, word: &str, count: impl Count) -> String {
+        self.with_count(word, count, " ")
+    }
+
+    /// Pluralize or singularize a word based on the count, without including the count.
+    ///
+    /// See [pluralize_word](crate::pluralize_word).
+    pub fn pluralize_word(&self, word: &str, count: impl Count) -> String {
+        if self.takes_singular(count.saturating_isize()) {
+            self.singular(word)
+        } else {
+            self.plural(word)
+        }
+    }
+
+    /// Pluralize or singularize a word based on the count, placing the separator after the count.
+    ///
+    /// See [pluralize_with_separator](crate::pluralize_with_separator).
+    pub fn pluralize_with_separator(&self, word: &str, count: isize, separator: &str) -> String {
+        self.with_count(word, count, separator)
+    }
+
+    fn with_count(&self, word: &str, count: impl Count, separator: &str) -> String {
+        if is_blank(word) {
+            return self.format_count(count);
+        }
+
+        let pluralized = self.pluralize_word(word, count);
+
+        format!("{}{}{}", self.format_count(count), separator, pluralized)
+    }
+
+    /// Wrap the count in bidi isolates when enabled.
+    pub(crate) fn format_count(&self, count: impl Display) -> String {
+        if self.bidi_isolation {
+            format!("\u{2066}{}\u{2069}", count)
+        } else {
+            count.to_string()
+        }
+    }
+
+    /// Pluralize or singularize a word based on the count, forcing the casing of the result.
+    ///
+    /// See [pluralize_cased](crate::pluralize_cased).
+    pub fn pluralize_cased(&self, word: &str, count: isize, case: CaseMode) -> String {
+        case.apply_from(word, self.pluralize_word(word, count))
+    }
+
+    /// Pluralize or singularize a word based on the count, placing the count after the word.
+    ///
+    /// See [pluralize_count_after](crate::pluralize_count_after).
+    pub fn pluralize_count_after(&self, word: &str, count: isize) -> String {
+        if is_blank(word) {
+            return self.format_count(count);
+        }
+
+        format!(
+            "{} {}",
+            self.pluralize_word(word, count),
+            self.format_count(count)
+        )
+    }
+
+    /// Pluralize or singularize a word for a range of counts, prefixing it with the range.
+    ///
+    /// See [pluralize_range](crate::pluralize_range).
+    pub fn pluralize_range(&self, word: &str, low: isize, high: isize) -> String {
+        let word = self.pluralize_if(
+            word,
+            !(self.takes_singular(low) && self.takes_singular(high)),
+        );
+
+        if low == high {
+            format!("{} {}", self.format_count(low), word)
+        } else {
+            let range = format!("{}\u{2013}{}", low, high);
+
+            format!("{} {}", self.format_count(range), word)
+        }
+    }
+
+    /// Pluralize or singularize a word that may already start with a count, replacing that count.
+    ///
+    /// See [repluralize](crate::repluralize).
+    pub fn repluralize(&self, word_with_count: &str, count: isize) -> String {
+        self.with_count(strip_count(word_with_count), count, " ")
+    }
+
+    /// Split the singular and plural forms of a word into their differing suffixes.
+    ///
+    /// See [suffix_delta](crate::suffix_delta).
+    pub fn suffix_delta(&self, word: &str) -> (String, String) {
+        let singular = self.singular(word);
+        let plural = self.plural(word);
+        let stem = self.stem_len(word, &singular, &plural);
+
+        (singular[stem..].to_string(), plural[stem..].to_string())
+    }
+
+    /// Length of the stem shared by both forms of a word.
+    ///
+    /// Irregular forms only share a stem when the plural extends the singular, like "oxen", so
+    /// "people" isn't split after the "pe" it happens to share with "person".
+    fn stem_len(&self, word: &str, singular: &str, plural: &str) -> usize {
+        let irregular = matches!(
+            self.decide(word, Form::Plural),
+            Decision::Irregular | Decision::Kept
+        );
+
+        if irregular && !plural.starts_with(singular) {
+            0
+        } else {
+            common_prefix_len(singular, plural)
+        }
+    }
+
+    /// Pluralize or singularize a word based on the count, split into its stem and suffix.
+    ///
+    /// See [pluralize_parts](crate::pluralize_parts).
+    pub fn pluralize_parts(&self, word: &str, count: impl Count) -> (String, String) {
+        let singular = self.singular(word);
+        let plural = self.plural(word);
+        let stem = self.stem_len(word, &singular, &plural);
+        let mut inflected = if self.takes_singular(count.saturating_isize()) {
+            singular
+        } else {
+            plural
+        };
+
+        if stem == 0 {
+            return (inflected, String::new());
+        }
+
+        let suffix = inflected.split_off(stem);
+
+        (inflected, suffix)
+    }
+
+    /// Compute both forms of a word at once.
+    ///
+    /// See [inflect](crate::inflect).
+    pub fn inflect(&self, word: &str) -> Inflection {
+        Inflection::new(
+            self.singular(word),
+            self.plural(word),
+            self.singular_predicate,
+        )
+    }
+
+    /// Pluralize or singularize a word based on a floating-point count.
+    ///
+    /// See [pluralize_f64](crate::pluralize_f64).
+    pub fn pluralize_f64(&self, word: &str, count: f64, include_count: bool) -> String {
+        let pluralized = if count.abs() == 1.0 {
+            self.singular(word)
+        } else {
+            self.plural(word)
+        };
+
+        if include_count {
+            format!("{} {}", self.format_count(count), pluralized)
+        } else {
+            pluralized
+        }
+    }
+
+    /// Prefix a unit with a value, pluralizing spelled out units but keeping symbols unchanged.
+    ///
+    /// See [pluralize_unit](crate::pluralize_unit).
+    pub fn pluralize_unit(&self, value: f64, unit: &str) -> String {
+        if self.unit_symbols.iter().any(|symbol| symbol == unit) {
+            format!("{} {}", self.format_count(value), unit)
+        } else {
+            self.pluralize_f64(unit, value, true)
+        }
+    }
+
+    /// Add a unit symbol that is kept unchanged by [pluralize_unit](Pluralizer::pluralize_unit).
+    ///
+    /// See [add_unit_symbol](crate::add_unit_symbol).
+    pub fn add_unit_symbol(&mut self, symbol: String) {
+        if !self.unit_symbols.contains(&symbol) {
+            self.unit_symbols.push(symbol);
+        }
+    }
+
+    /// Pluralize or singularize a word based on the passed in count, borrowing the word when it
+    /// doesn't change.
+    ///
+    /// See [pluralize_cow](crate::pluralize_cow).
+    pub fn pluralize_cow<'a>(&self, word: &'a str, count: isize) -> Cow<'a, str> {
+        if self.takes_singular(count) {
+            self.replace_word(word, Form::Singular)
+        } else {
+            self.replace_word(word, Form::Plural)
+        }
+    }
+
+    /// Pick the verb agreeing with the count.
+    ///
+    /// See [verb_for_count](crate::verb_for_count).
+    pub fn verb_for_count<'a>(
+        &self,
+        singular_verb: &'a str,
+        plural_verb: &'a str,
+        count: isize,
+    ) -> &'a str {
+        if self.takes_singular(count) {
+            singular_verb
+        } else {
+            plural_verb
+        }
+    }
+
+    /// Prefix a subject with "this" or "these" depending on the count.
+    ///
+    /// See [agree](crate::agree).
+    pub fn agree(&self, subject: &str, count: isize) -> String {
+        let demonstrative = self.verb_for_count("this", "these", count);
+
+        format!(
+            "{} {}",
+            demonstrative,
+            self.pluralize_phrase(subject, count)
+        )
+    }
+
+    /// Pluralize or singularize the head noun of a phrase based on the passed in count.
+    ///
+    /// See [pluralize_phrase](crate::pluralize_phrase).
+    pub fn pluralize_phrase(&self, phrase: &str, count: isize) -> String {
+        let form = if self.takes_singular(count) {
+            Form::Singular
+        } else {
+            Form::Plural
+        };
+
+        let spans = word_spans(phrase);
+        let words = spans
+            .iter()
+            .map(|&(start, end)| &phrase[start..end])
+            .collect::<Vec<&str>>();
+
+        let (start, end) = match self.head_index(&words) {
+            Some(i) => spans[i],
+            None => match spans.last() {
+                Some(&span) => span,
+                None => return phrase.to_string(),
+            },
+        };
+
+        format!(
+            "{}{}{}",
+            &phrase[..start],
+            self.replace_word(&phrase[start..end], form),
+            &phrase[end..]
+        )
+    }
+
+    /// Pluralize or singularize the words of a text matching the predicate.
+    ///
+    /// See [pluralize_tokens](crate::pluralize_tokens).
+    pub fn pluralize_tokens(
+        &self,
+        text: &str,
+        count: isize,
+        predicate: impl Fn(&str) -> bool,
+    ) -> String {
+        let form = if self.takes_singular(count) {
+            Form::Singular
+        } else {
+            Form::Plural
+        };
+
+        let mut result = String::with_capacity(text.len());
+        let mut end = 0;
+
+        for (start, token_end) in token_spans(text) {
+            let token = &text[start..token_end];
+
+            result.push_str(&text[end..start]);
+
+            if predicate(token) {
+                result.push_str(&self.replace_word(token, form));
+            } else {
+                result.push_str(token);
+            }
+
+            end = token_end;
+        }
+
+        result.push_str(&text[end..]);
+        result
+    }
+
+    /// Find the head noun of a compound, which is the word before the first preposition followed
+    /// by another word, or before the first postpositive.
+    fn head_index(&self, words: &[&str]) -> Option<usize> {
+        (1..words.len()).find_map(|i| {
+            let word = words[i].to_lowercase();
+
+            let is_preposition = i + 1 < words.len() && self.prepositions.contains(&word);
+
+            if is_preposition || self.postpositives.contains(&word) {
+                Some(i - 1)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Convert a word to its plural form.
+    ///
+    /// See [plural](crate::plural).
+    pub fn plural(&self, word: &str) -> String {
+        self.replace_word(word, Form::Plural).into_owned()
+    }
+
+    /// Convert a word to its singular form.
+    ///
+    /// See [singular](crate::singular).
+    pub fn singular(&self, word: &str) -> String {
+        self.replace_word(word, Form::Singular).into_owned()
+    }
+
+    /// Check that singularizing the plural of every word gives back the word.
+    ///
+    /// See [verify_roundtrip](crate::verify_roundtrip).
+    pub fn verify_roundtrip(&self, words: &[&str]) -> Vec<RoundtripFailure> {
+        words
+            .iter()
+            .filter_map(|&word| {
+                let plural = self.plural(word);
+                let singular = self.singular(&plural);
+
+                (singular != word).then(|| RoundtripFailure {
+                    word: word.to_string(),
+                    plural,
+                    singular,
+                })
+            })
+            .collect()
+    }
+
+    /// List the plural rules matching a word along with their result.
+    ///
+    /// See [test_plural_rules](crate::test_plural_rules).
+    pub fn test_plural_rules(&self, word: &str) -> Vec<(String, String)> {
+        self.matching_rules(word, &self.plural_rules)
+    }
+
+    /// List the singular rules matching a word along with their result.
+    ///
+    /// See [test_singular_rules](crate::test_singular_rules).
+    pub fn test_singular_rules(&self, word: &str) -> Vec<(String, String)> {
+        self.matching_rules(word, &self.singular_rules)
+    }
+
+    /// Convert a CamelCase type name to its snake_case plural table name.
+    ///
+    /// See [tableize](crate::tableize).
+    pub fn tableize(&self, type_name: &str) -> String {
+        inflections::map_last_word(&inflections::underscore(type_name), |word| {
+            self.plural(word)
+        })
+    }
+
+    /// Convert a snake_case table name to its CamelCase singular type name.
+    ///
+    /// See [classify](crate::classify).
+    pub fn classify(&self, table_name: &str) -> String {
+        inflections::camelize(&inflections::map_last_word(table_name, |word| {
+            self.singular(word)
+        }))
+    }
+
+    /// Convert a word to its plural form and report the rule that decided it.
+    ///
+    /// See [explain_plural](crate::explain_plural).
+    pub fn explain_plural(&self, word: &str) -> Transformation {
+        self.explain(word, Form::Plural)
+    }
+
+    /// Convert a word to its singular form and report the rule that decided it.
+    ///
+    /// See [explain_singular](crate::explain_singular).
+    pub fn explain_singular(&self, word: &str) -> Transformation {
+        self.explain(word, Form::Singular)
+    }
+
+    fn explain(&self, word: &str, form: Form) -> Transformation {
+        Transformation {
+            result: self.replace_word(word, form).into_owned(),
+            source: self.decide(word, form).into(),
+        }
+    }
+
+    /// Find what decides the form of a word, without converting it.
+    fn decide(&self, word: &str, form: Form) -> Decision<'_> {
+        // Follow the same steps as `replace_uncached` down to the noun deciding the result.
+        let (_, mut core) = split_affixes(word);
+
+        if let Some((base, _, _)) = split_possessive(core) {
+            core = base;
+        }
+
+        let parts = core.split('-').collect::<Vec<&str>>();
+
+        if let Some(head) = self.head_index(&parts) {
+            core = parts[head];
+        }
+
+        self.resolve_core(core, form).1
+    }
+
+    /// Convert a word to its plural form when `plural` is true and to its singular form otherwise.
+    ///
+    /// See [pluralize_if](crate::pluralize_if).
+    pub fn pluralize_if(&self, word: &str, plural: bool) -> String {
+        if plural {
+            self.plural(word)
+        } else {
+            self.singular(word)
+        }
+    }
+
+    /// Write a word for an unknown count, e.g. "item(s)".
+    ///
+    /// See [pluralize_paren](crate::pluralize_paren).
+    pub fn pluralize_paren(&self, word: &str) -> String {
+        let singular = self.singular(word);
+        let plural = self.plural(word);
+
+        match plural.strip_prefix(singular.as_str()) {
+            Some("") => singular,
+            Some(suffix) => format!("{}({})", singular, suffix),
+            None => format!("{}/{}", singular, plural),
+        }
+    }
+
+    /// Pluralize or singularize every word in a slice based on the passed in count.
+    ///
+    /// See [pluralize_all](crate::pluralize_all).
+    pub fn pluralize_all(&self, words: &[&str], count: isize) -> Vec<String> {
+        if self.takes_singular(count) {
+            self.singular_all(words)
+        } else {
+            self.plural_all(words)
+        }
+    }
+
+    /// Convert every word in a slice to its plural form.
+    ///
+    /// See [plural_all](crate::plural_all).
+    pub fn plural_all(&self, words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| self.plural(word)).collect()
+    }
+
+    /// Convert every word in a slice to its singular form.
+    ///
+    /// See [singular_all](crate::singular_all).
+    pub fn singular_all(&self, words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| self.singular(word)).collect()
+    }
+
+    /// Check whether a word is already in its plural form.
+    ///
+    /// See [is_plural](crate::is_plural).
+    pub fn is_plural(&self, word: &str) -> bool {
+        self.plural(word).to_lowercase() == word.to_lowercase()
+    }
+
+    /// Check whether a word is already in its singular form.
+    ///
+    /// See [is_singular](crate::is_singular).
+    pub fn is_singular(&self, word: &str) -> bool {
+        self.singular(word).to_lowercase() == word.to_lowercase()
+    }
+
+    /// Check if a rule was added by an uncountable regex, which is stored as a rule keeping the
+    /// whole match.
+    fn is_uncountable_rule(&self, rule: &WordRule) -> bool {
+        rule.placement == "$0"
+            && self
+                .uncountable_regexes
+                .iter()
+                .any(|r| r.as_str() == rule.rule.as_str())
+    }
+
+    fn sanitize_word<'a, 's>(
+        &'s self,
+        token: String,
+        word: &'a str,
+        rules: &'s RuleList,
+    ) -> (Cow<'a, str>, Decision<'s>) {
+        // Empty string or doesn't need fixing.
+        if token.is_empty() {
+            return (Cow::Borrowed(word), Decision::Unchanged);
+        }
+
+        if self.uncountable_rules.contains(&token)
+            || self.exact_uncountable_rules.iter().any(|w| w == word)
+        {
+            return (Cow::Borrowed(word), Decision::Uncountable);
+        }
+
+        // Use the last sanitization rule to match, so rules added later take precedence.
+        if let Some(word_rule) = rules.last_match(word) {
+            let str = self.apply_rule(word_rule, word);
+            let decision = if self.is_uncountable_rule(word_rule) {
+                Decision::Uncountable
+            } else {
+                Decision::Rule(word_rule)
+            };
+
+            return (reuse_word(word, str), decision);
+        }
+
+        (Cow::Borrowed(word), Decision::Unchanged)
+    }
+
+    fn apply_rule(&self, word_rule: &WordRule, word: &str) -> String {
+        let str = word_rule.rule.replace(word, |caps: &regex::Captures| {
+            // Acronyms only take a lower cased suffix when nothing of them is replaced, e.g.
+            // "URLs" but "LUCES".
+            let placement = if self.acronym_mode && caps[0].is_empty() && is_acronym(word) {
+                word_rule.placement.to_lowercase()
+            } else {
+                restore_suffix_case(word, &word_rule.placement)
+            };
+
+            // Captured text is taken from the word itself, so it keeps its original casing,
+            // e.g. "userIndex" becomes "userIndices".
+            expand_placement(&placement, caps)
+        });
+
+        str.into_owned()
+    }
+
+    /// List every rule matching the word, from the one taking precedence to the one tried last.
+    fn matching_rules(&self, word: &str, rules: &RuleList) -> Vec<(String, String)> {
+        let mut matches = rules
+            .iter()
+            .filter(|rule| rule.rule.is_match(word))
+            .map(|rule| (rule.rule.as_str().to_string(), self.apply_rule(rule, word)))
+            .collect::<Vec<(String, String)>>();
+
+        matches.reverse();
+        matches
+    }
+
+    fn replace_word<'a>(&self, word: &'a str, form: Form) -> Cow<'a, str> {
+        // Nothing to replace, so don't bother with the cache or the rules.
+        if is_blank(word) || self.max_word_len.is_some_and(|max| word.len() > max) {
+            return Cow::Borrowed(word);
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(result) = self.cache.get(word, form) {
+                return reuse_word(word, result);
+            }
+
+            let result = self.replace_uncached(word, form);
+            self.cache.insert(word, form, &result);
+
+            result
+        }
+
+        #[cfg(not(feature = "cache"))]
+        self.replace_uncached(word, form)
+    }
+
+    fn replace_uncached<'a>(&self, word: &'a str, form: Form) -> Cow<'a, str> {
+        let (start, core) = split_affixes(word);
+
+        if self.is_contraction(core) {
+            return Cow::Borrowed(word);
+        }
+
+        let replaced = match self.replace_possessive(core, form) {
+            Some(replaced) => replaced,
+            None => self.replace_noun(core, form),
+        };
+
+        match replaced {
+            Cow::Borrowed(_) => Cow::Borrowed(word),
+            Cow::Owned(replaced) => Cow::Owned(format!(
+                "{}{}{}",
+                &word[..start],
+                replaced,
+                &word[start + core.len()..]
+            )),
+        }
+    }
+
+    fn is_contraction(&self, word: &str) -> bool {
+        // Only words with an apostrophe can be contractions, so skip the lowercasing otherwise.
+        word.contains(is_apostrophe) && self.contractions.contains(&normalize_contraction(word))
+    }
+
+    /// Replace the noun of a possessive, e.g. "child's" or "cats'", keeping the clitic.
+    fn replace_possessive<'a>(&self, word: &'a str, form: Form) -> Option<Cow<'a, str>> {
+        let (base, apostrophe, s) = split_possessive(word)?;
+
+        // A bare apostrophe also follows singular nouns ending in `s`, e.g. "James'", so only
+        // plurals have theirs replaced. Names can't be told apart by spelling and are kept.
+        if s.is_none() && form == Form::Singular && !self.is_plural_possessor(base) {
+            return Some(Cow::Borrowed(word));
+        }
+
+        let mut replaced = self.replace_noun(base, form);
+
+        // An upper cased clitic means the whole word is upper cased rather than an acronym, e.g.
+        // "CAT'S" becomes "CATS'" and not "CATs'".
+        if s == Some('S') && base == base.to_uppercase() {
+            replaced = Cow::Owned(replaced.to_uppercase());
+        }
+
+        // Plurals ending in `s` only take the apostrophe, e.g. "dogs'".
+        let ends_with_s = replaced.ends_with(['s', 'S']);
+        let s = s.unwrap_or(if replaced == replaced.to_uppercase() {
+            'S'
+        } else {
+            's'
+        });
+
+        let possessive = if form == Form::Plural && ends_with_s {
+            format!("{}{}", replaced, apostrophe)
+        } else {
+            format!("{}{}{}", replaced, apostrophe, s)
+        };
+
+        Some(reuse_word(word, possessive))
+    }
+
+    /// Check if the noun before a bare apostrophe is a plural rather than a name like "James".
+    fn is_plural_possessor(&self, base: &str) -> bool {
+        let mut chars = base.chars();
+        let name = chars.next().is_some_and(char::is_uppercase) && chars.any(char::is_lowercase);
+
+        !name && self.is_plural(base) && self.singular(base) != base
+    }
+
+    fn replace_noun<'a>(&self, word: &'a str, form: Form) -> Cow<'a, str> {
+        match self.replace_compound(word, form) {
+            Some(replaced) => replaced,
+            None => self.replace_core(word, form),
+        }
+    }
+
+    /// Replace the principal word of a hyphenated compound, e.g. "mother-in-law".
+    fn replace_compound<'a>(&self, word: &'a str, form: Form) -> Option<Cow<'a, str>> {
+        if !word.contains('-') {
+            return None;
+        }
+
+        let parts = word.split('-').collect::<Vec<&str>>();
+        let head = self.head_index(&parts)?;
+
+        Some(match self.replace_core(parts[head], form) {
+            Cow::Borrowed(_) => Cow::Borrowed(word),
+            Cow::Owned(replaced) => {
+                let mut parts = parts.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+                parts[head] = replaced;
+
+                Cow::Owned(parts.join("-"))
+            }
+        })
+    }
+
+    fn replace_core<'a>(&self, word: &'a str, form: Form) -> Cow<'a, str> {
+        let (replaced, decision) = self.resolve_core(word, form);
+        self.report(decision);
+
+        replaced
+    }
+
+    #[cfg(feature = "metrics")]
+    fn report(&self, decision: Decision<'_>) {
+        if let Some(hook) = &self.metrics_hook {
+            hook.report(Path::from(decision));
+        }
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    fn report(&self, _decision: Decision<'_>) {}
+
+    /// Replace a single word, returning the decision that produced the result.
+    fn resolve_core<'a>(&self, word: &'a str, form: Form) -> (Cow<'a, str>, Decision<'_>) {
+        let (replace_map, keep_map, rules) = match form {
+            Form::Singular => (
+                &self.irregular_plurals,
+                &self.irregular_singles,
+                &self.singular_rules,
+            ),
+            Form::Plural => (
+                &self.irregular_singles,
+                &self.irregular_plurals,
+                &self.plural_rules,
+            ),
+        };
+        let (classical_replace, classical_keep) = match form {
+            Form::Singular => (&self.classical_plurals, &self.classical_singles),
+            Form::Plural => (&self.classical_singles, &self.classical_plurals),
+        };
+
+        // Get the correct token and case restoration functions.
+        let token = word.to_lowercase();
+
+        // Check against the keep object map.
+        if keep_map.contains_key(&token) || classical_keep.contains_key(&token) {
+            return (reuse_word(word, restore_case(word, &token)), Decision::Kept);
+        }
+
+        // Check against the replacement map for a direct word replacement.
+        // Irregular rules take precedence over the classical plurals.
+        if let Some(token) = replace_map.get(&*token) {
+            return (
+                reuse_word(word, restore_case(word, token)),
+                Decision::Irregular,
+            );
+        }
+
+        if let Some(token) = classical_replace.get(&*token) {
+            return (
+                reuse_word(word, restore_case(word, token)),
+                Decision::Classical,
+            );
+        }
+
+        // Words ending in `o` or `f` that only take an `s`, e.g. "photos" rather than "photoes".
+        if form == Form::Plural && self.s_exceptions.contains(&token) {
+            let plural = format!("{}{}", word, restore_suffix_case(word, "s"));
+
+            return (Cow::Owned(plural), Decision::Irregular);
+        }
+
+        // Strict mode never guesses, so words without an irregular rule are kept as is.
+        if self.strict_mode {
+            return (Cow::Borrowed(word), Decision::Unchanged);
+        }
+
+        // Plural acronyms only drop their suffix, e.g. "CPUs".
+        if let Some(acronym) = word.strip_suffix('s') {
+            if form == Form::Singular && self.acronym_mode && is_acronym(acronym) {
+                return (Cow::Owned(acronym.to_string()), Decision::Acronym);
+            }
+        }
+
+        // Run all the rules against the word.
+        self.sanitize_word(token, word, rules)
+    }
+}
+
+/// Check if a word is empty or only made of whitespace.
+fn is_blank(word: &str) -> bool {
+    word.trim().is_empty()
+}
+
+/// Length in bytes of the longest prefix shared by both strings.
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((index, _), _)| index)
+}
+
+/// Strip a leading integer and the whitespace after it, keeping the word as is when the leading
+/// token isn't an integer.
+fn strip_count(word: &str) -> &str {
+    let trimmed = word.trim_start();
+    let (token, rest) = trimmed
+        .split_once(char::is_whitespace)
+        .unwrap_or((trimmed, ""));
+    let digits = token.strip_prefix(['-', '+']).unwrap_or(token);
+
+    if !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        rest.trim_start()
+    } else {
+        word
+    }
+}
+
+/// Split off surrounding whitespace and punctuation, returning the offset of the word and the word.
+fn split_affixes(word: &str) -> (usize, &str) {
+    let start = word.len() - word.trim_start_matches(is_affix).len();
+
+    (start, word[start..].trim_end_matches(is_affix))
+}
+
+/// Split a possessive into its noun, apostrophe and the `s` of the clitic when present.
+fn split_possessive(word: &str) -> Option<(&str, char, Option<char>)> {
+    let mut chars = word.chars().rev();
+    let last = chars.next()?;
+
+    if is_apostrophe(last) {
+        // Only plurals ending in `s` take a bare apostrophe, e.g. "cats'".
+        let base = &word[..word.len() - last.len_utf8()];
+
+        return base.ends_with(['s', 'S']).then_some((base, last, None));
+    }
+
+    let apostrophe = chars.next()?;
+
+    if matches!(last, 's' | 'S') && is_apostrophe(apostrophe) {
+        let base = &word[..word.len() - last.len_utf8() - apostrophe.len_utf8()];
+
+        return (!base.is_empty()).then_some((base, apostrophe, Some(last)));
+    }
+
+    None
+}
+
+fn is_apostrophe(c: char) -> bool {
+    matches!(c, '\'' | '’')
+}
+
+/// Lowercase a contraction and use a straight apostrophe, e.g. "It’s" becomes "it's".
+fn normalize_contraction(word: &str) -> String {
+    word.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| if is_apostrophe(c) { '\'' } else { c })
+        .collect()
+}
+
+/// Byte ranges of the whitespace separated words in a text.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+
+    spans
+}
+
+/// Byte ranges of the words in a text, which are runs of letters and digits that may be joined by
+/// apostrophes or hyphens, e.g. "mother-in-law's".
+fn token_spans(text: &str) -> Vec<(usize, usize)> {
+    let chars = text.char_indices().collect::<Vec<(usize, char)>>();
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        let joins = |c: char| is_apostrophe(c) || c == '-';
+        let is_word = c.is_alphanumeric()
+            || (joins(c)
+                && start.is_some()
+                && chars
+                    .get(i + 1)
+                    .is_some_and(|&(_, next)| next.is_alphanumeric()));
+
+        match (is_word, start) {
+            (true, None) => start = Some(offset),
+            (false, Some(s)) => {
+                spans.push((s, offset));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+
+    spans
+}
+
+/// Characters surrounding a word that are kept untouched, e.g. `"  house."`.
+fn is_affix(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '.' | ',' | '!' | '?' | ';' | ':' | '(' | ')' | '"')
+}
+
+/// Parse the capture group reference following a `$`, either `1`, `12` or `{12}`, returning the
+/// group and the length of the reference.
+///
+/// Digits are read greedily like `regex` does, but a reference ends at the first non-digit, so
+/// "$1es" is group 1 followed by "es".
+fn parse_group(reference: &str) -> Option<(usize, usize)> {
+    if let Some(braced) = reference.strip_prefix('{') {
+        let (digits, _) = braced.split_once('}')?;
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        return Some((digits.parse().ok()?, digits.len() + 2));
+    }
+
+    let len = reference.bytes().take_while(u8::is_ascii_digit).count();
+
+    Some((reference[..len].parse().ok()?, len))
+}
+
+/// Substitute the capture group references of a placement, groups that didn't take part in the
+/// match are replaced with nothing.
+fn expand_placement(placement: &str, caps: &regex::Captures) -> String {
+    let mut result = String::with_capacity(placement.len());
+    let mut rest = placement;
+
+    while let Some(i) = rest.find('$') {
+        result.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+
+        match parse_group(rest) {
+            Some((group, len)) => {
+                result.push_str(caps.get(group).map_or("", |m| m.as_str()));
+                rest = &rest[len..];
+            }
+            None => result.push('$'),
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Compile configured rules, checking their placements.
+fn compile_rules(rules: &[RegexRule]) -> Result<Vec<WordRule>, (&str, RuleError)> {
+    rules
+        .iter()
+        .map(|rule| {
+            Regex::new(&rule.pattern)
+                .map_err(RuleError::from)
+                .and_then(|regex| {
+                    validate_placement(&regex, &rule.placement)?;
+
+                    Ok(WordRule {
+                        rule: regex,
+                        placement: rule.placement.clone(),
+                    })
+                })
+                .map_err(|error| (rule.pattern.as_str(), error))
+        })
+        .collect()
+}
+
+/// Check that every `$n` reference in the placement has a matching capture group.
+fn validate_placement(rule: &Regex, placement: &str) -> Result<(), RuleError> {
+    let groups = rule.captures_len() - 1;
+
+    for (i, _) in placement.match_indices('$') {
+        if let Some((group, _)) = parse_group(&placement[i + 1..]) {
+            if group > groups {
+                return Err(RuleError::MissingCaptureGroup { group, groups });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that a rule ends with a `$` or `\z` anchor, possibly inside closing groups.
+fn validate_anchor(rule: &Regex) -> Result<(), RuleError> {
+    let pattern = rule.as_str().trim_end_matches(')');
+    let escapes = |anchor: &str| {
+        pattern
+            .strip_suffix(anchor)
+            .map(|rest| rest.len() - rest.trim_end_matches('\\').len())
+    };
+
+    // An anchor is only an anchor when preceded by an even number of backslashes, e.g. `\$` is a
+    // literal dollar sign.
+    match (escapes("$"), escapes(r"\z")) {
+        (Some(backslashes), _) | (_, Some(backslashes)) if backslashes % 2 == 0 => Ok(()),
+        _ => Err(RuleError::Unanchored),
+    }
+}
+
+/// Whether a count takes the singular form, which is the case for `1` and `-1`.
+fn is_singular_count(count: isize) -> bool {
+    // `unsigned_abs` can't overflow on `isize::MIN`, unlike `abs`.
+    count.unsigned_abs() == 1
+}
+
+/// Borrow the original word when the transformation left it untouched.
+fn reuse_word(word: &str, result: String) -> Cow<'_, str> {
+    if result == word {
+        Cow::Borrowed(word)
+    } else {
+        Cow::Owned(result)
+    }
+}
+
+/// Check if a word looks like an acronym, i.e. two to four upper cased letters or digits.
+fn is_acronym(word: &str) -> bool {
+    (2..=4).contains(&word.chars().count())
+        && word.chars().any(char::is_uppercase)
+        && word.chars().all(|c| c.is_uppercase() || c.is_ascii_digit())
+}
+
+/// Case the text a rule appends so it blends with the word, e.g. "s" for "userId" but "S" for
+/// "USER".
+fn restore_suffix_case(word: &str, suffix: &str) -> String {
+    if word.eq(&word.to_lowercase()) || !word.eq(&word.to_uppercase()) {
+        return suffix.to_lowercase();
+    }
+
+    suffix.to_uppercase()
+}
+
+fn restore_case(word: &str, token: &str) -> String {
+    // Tokens are an exact match.
+    if word.eq(token) {
+        return token.to_string();
+    }
+
+    // Lower cased words. E.g. "hello".
+    if word.eq(&word.to_lowercase()) {
+        return token.to_lowercase();
+    }
+
+    // Upper cased words. E.g. "WHISKY".
+    if word.eq(&word.to_uppercase()) {
+        return token.to_uppercase();
+    }
+
+    // Title cased words. E.g. "Title". Built from chars so multibyte letters like "Éclair" or
+    // "Яблоко" are never split.
+    if word.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = token.chars();
+
+        if let Some(first) = chars.next() {
+            return first.to_uppercase().chain(chars).collect();
+        }
+    }
+
+    // Lower cased words. E.g. "test".
+    token.to_lowercase()
+}
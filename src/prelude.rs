@@ -0,0 +1,41 @@
+//! Extension trait to pluralize strings with method calls.
+//!
+//! # Examples
+//! ```
+//! use pluralizer::prelude::*;
+//!
+//! "House".to_plural(); // Houses
+//! "Houses".to_singular(); // House
+//! "House".pluralize(2); // Houses
+//! ```
+
+use alloc::string::String;
+
+/// Pluralization methods for anything that can be viewed as a `str`.
+///
+/// Every method forwards to the crate level function of the same purpose.
+pub trait Pluralize {
+    /// Convert to the plural form. See [plural](crate::plural).
+    fn to_plural(&self) -> String;
+
+    /// Convert to the singular form. See [singular](crate::singular).
+    fn to_singular(&self) -> String;
+
+    /// Pluralize or singularize based on the passed in count, without including the count.
+    /// See [pluralize_word](crate::pluralize_word).
+    fn pluralize(&self, count: isize) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> Pluralize for T {
+    fn to_plural(&self) -> String {
+        crate::plural(self.as_ref())
+    }
+
+    fn to_singular(&self) -> String {
+        crate::singular(self.as_ref())
+    }
+
+    fn pluralize(&self, count: isize) -> String {
+        crate::pluralize_word(self.as_ref(), count)
+    }
+}
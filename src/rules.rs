@@ -0,0 +1,177 @@
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use regex::{Regex, RegexSet};
+
+#[derive(Debug, Clone)]
+pub(crate) struct WordRule {
+    pub(crate) rule: Regex,
+    pub(crate) placement: String,
+}
+
+/// Where a rule is added among the existing rules.
+///
+/// Rules are tried from the last one added to the first, and the first match wins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// Add the rule before every other rule, so any existing rule matching the word wins over it,
+    /// including the built-in ones.
+    First,
+    /// Add the rule after every other rule, so it wins over every existing rule matching the word.
+    /// This is how [add_plural_rule](crate::add_plural_rule) adds rules.
+    #[default]
+    Last,
+}
+
+/// Static rule tables a [Pluralizer](crate::Pluralizer) can be seeded from.
+///
+/// Rules are `(pattern, placement)` pairs and irregulars are `(singular, plural)` pairs.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RuleTables {
+    pub(crate) irregular: &'static [(&'static str, &'static str)],
+    pub(crate) plural: &'static [(&'static str, &'static str)],
+    pub(crate) singular: &'static [(&'static str, &'static str)],
+    pub(crate) uncountable: &'static [&'static str],
+    pub(crate) uncountable_regex: &'static [&'static str],
+    pub(crate) prepositions: &'static [&'static str],
+    pub(crate) postpositives: &'static [&'static str],
+    pub(crate) o_exceptions: &'static [&'static str],
+    pub(crate) f_exceptions: &'static [&'static str],
+    pub(crate) contractions: &'static [&'static str],
+    pub(crate) collectives: &'static [(&'static str, &'static str)],
+    pub(crate) unit_symbols: &'static [&'static str],
+}
+
+/// Lazily compiled [RegexSet] of a [RuleList], `None` when the set exceeds the regex size limits.
+#[cfg(feature = "std")]
+type CompiledSet = std::sync::OnceLock<Option<Arc<RegexSet>>>;
+#[cfg(not(feature = "std"))]
+type CompiledSet = spin::Once<Option<Arc<RegexSet>>>;
+
+/// Ordered collection of rules where the last matching rule wins.
+///
+/// The rules are also compiled into a [RegexSet] on first use, so finding the matching rule takes
+/// a single search instead of one per rule. The set is rebuilt after the rules change and shared
+/// with clones of the list.
+///
+/// The rules the list is created with are its built-in rules. Rules added afterwards either go
+/// before them, counted by `first`, or after them, so the built-in rules are always the `builtin`
+/// rules starting at index `first` and the others can be cleared without recompiling them.
+#[derive(Debug, Default)]
+pub(crate) struct RuleList {
+    rules: Vec<WordRule>,
+    first: usize,
+    builtin: usize,
+    set: CompiledSet,
+}
+
+impl Clone for RuleList {
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+            first: self.first,
+            builtin: self.builtin,
+            set: match self.set.get() {
+                Some(set) => CompiledSet::from(set.clone()),
+                None => CompiledSet::new(),
+            },
+        }
+    }
+}
+
+impl From<Vec<WordRule>> for RuleList {
+    fn from(rules: Vec<WordRule>) -> Self {
+        Self {
+            first: 0,
+            builtin: rules.len(),
+            rules,
+            set: CompiledSet::new(),
+        }
+    }
+}
+
+impl RuleList {
+    /// Add a rule unless an identical pattern and placement is already in the list.
+    pub(crate) fn push(&mut self, rule: WordRule) {
+        if !self.contains(&rule) {
+            self.rules.push(rule);
+            self.set = CompiledSet::new();
+        }
+    }
+
+    /// Add a rule before every other rule, so it has the lowest precedence.
+    pub(crate) fn insert_first(&mut self, rule: WordRule) {
+        if !self.contains(&rule) {
+            self.rules.insert(0, rule);
+            self.first += 1;
+            self.set = CompiledSet::new();
+        }
+    }
+
+    /// Remove every rule added after the list was created, keeping the built-in rules.
+    pub(crate) fn clear_added(&mut self) {
+        if self.rules.len() != self.builtin {
+            self.rules.truncate(self.first + self.builtin);
+            self.rules.drain(..self.first);
+            self.first = 0;
+            self.set = CompiledSet::new();
+        }
+    }
+
+    /// Remove the rule with an identical pattern and placement, returning if it was in the list.
+    pub(crate) fn remove(&mut self, rule: &WordRule) -> bool {
+        let index = self
+            .rules
+            .iter()
+            .position(|r| r.rule.as_str() == rule.rule.as_str() && r.placement == rule.placement);
+        let Some(index) = index else {
+            return false;
+        };
+
+        self.rules.remove(index);
+        self.set = CompiledSet::new();
+
+        if index < self.first {
+            self.first -= 1;
+        } else if index < self.first + self.builtin {
+            self.builtin -= 1;
+        }
+
+        true
+    }
+
+    pub(crate) fn contains(&self, rule: &WordRule) -> bool {
+        self.rules
+            .iter()
+            .any(|r| r.rule.as_str() == rule.rule.as_str() && r.placement == rule.placement)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &WordRule> {
+        self.rules.iter()
+    }
+
+    /// Find the rule with the highest index matching the word.
+    pub(crate) fn last_match(&self, word: &str) -> Option<&WordRule> {
+        let compile = || {
+            RegexSet::new(self.rules.iter().map(|r| r.rule.as_str()))
+                .ok()
+                .map(Arc::new)
+        };
+
+        #[cfg(feature = "std")]
+        let set = self.set.get_or_init(compile);
+        #[cfg(not(feature = "std"))]
+        let set = self.set.call_once(compile);
+
+        match set {
+            Some(set) => set.matches(word).iter().next_back().map(|i| &self.rules[i]),
+            // Fall back to trying every rule when the set exceeds the regex size limits.
+            None => self.rules.iter().rev().find(|r| r.rule.is_match(word)),
+        }
+    }
+}
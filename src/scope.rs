@@ -0,0 +1,112 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use crate::{rejects_changes, update_pluralizer, Pluralizer, UncountableRule};
+
+/// Copy of every shared rule collection, taken by [snapshot](crate::snapshot).
+///
+/// Pass it to [restore](crate::restore) to bring the rules back to this state. Clone it to restore
+/// the same state more than once.
+#[derive(Debug, Clone)]
+pub struct RuleSnapshot(pub(crate) Pluralizer);
+
+/// Guard that removes the rules added through it when it goes out of scope.
+///
+/// Created by [scoped](crate::scoped). Rules added while the guard is alive are visible to every
+/// caller of the crate level functions, and are all discarded once it's dropped, including while
+/// unwinding from a panic. Only the rules added through the guard are undone: an irregular rule it
+/// replaced is put back, and rules added by other callers meanwhile are kept. Rules frozen with
+/// [freeze](crate::freeze) while the guard is alive stay, since frozen rules can't change.
+#[must_use = "the rules are restored as soon as the scope is dropped"]
+pub struct RuleScope {
+    added: RefCell<Vec<ScopedRule>>,
+}
+
+/// Rule added through a [RuleScope], along with what's needed to undo it.
+enum ScopedRule {
+    Irregular {
+        singular: String,
+        plural: String,
+        replaced: Option<String>,
+    },
+    Uncountable(UncountableRule),
+}
+
+impl RuleScope {
+    pub(crate) fn new() -> Self {
+        Self {
+            added: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Add an irregular word definition until the scope ends.
+    ///
+    /// See [add_irregular_rule](crate::add_irregular_rule).
+    pub fn add_irregular_rule(&self, singular: String, plural: String) {
+        let replaced = update_pluralizer(|p| {
+            let replaced = p.get_irregular_plural(&singular);
+            p.add_irregular_rule(singular.clone(), plural.clone());
+
+            replaced
+        });
+
+        self.added.borrow_mut().push(ScopedRule::Irregular {
+            singular,
+            plural,
+            replaced,
+        });
+    }
+
+    /// Add an uncountable word rule until the scope ends.
+    ///
+    /// See [add_uncountable_rule](crate::add_uncountable_rule).
+    pub fn add_uncountable_rule(&self, rule: UncountableRule) {
+        let added = update_pluralizer(|p| {
+            let added = !p.has_uncountable_rule(&rule);
+            p.add_uncountable_rule(rule.clone());
+
+            added
+        });
+
+        // Rules that were already there outlive the scope.
+        if added {
+            self.added.borrow_mut().push(ScopedRule::Uncountable(rule));
+        }
+    }
+}
+
+impl Drop for RuleScope {
+    fn drop(&mut self) {
+        let added = self.added.take();
+
+        // Frozen rules keep the scoped rules, rather than panicking while dropping the guard.
+        if added.is_empty() || rejects_changes() {
+            return;
+        }
+
+        update_pluralizer(|p| {
+            for rule in added.into_iter().rev() {
+                match rule {
+                    ScopedRule::Irregular {
+                        singular,
+                        plural,
+                        replaced,
+                    } => {
+                        // Keep the rule if it was replaced again since.
+                        if p.get_irregular_plural(&singular).as_ref() != Some(&plural) {
+                            continue;
+                        }
+
+                        p.remove_irregular_rule(&singular);
+
+                        if let Some(replaced) = replaced {
+                            p.add_irregular_rule(singular, replaced);
+                        }
+                    }
+                    ScopedRule::Uncountable(rule) => p.remove_uncountable_rule(&rule),
+                }
+            }
+        })
+    }
+}
@@ -687,6 +687,18 @@ mod test {
     use super::*;
     use crate::*;
 
+    use regex::Regex;
+    use std::sync::{Mutex, MutexGuard};
+
+    // Serializes the tests that replace the shared rules, so they can't undo each other's rules.
+    static SHARED_RULES: Mutex<()> = Mutex::new(());
+
+    fn lock_shared_rules() -> MutexGuard<'static, ()> {
+        SHARED_RULES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     #[test]
     fn can_convert_to_plural() {
         let mut tests = BASIC_TESTS.to_vec();
@@ -720,4 +732,2508 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn can_convert_without_count() {
+        for [singular_word, plural_word] in BASIC_TESTS {
+            assert_eq!(plural(singular_word), pluralize(singular_word, 5, false));
+            assert_eq!(singular(plural_word), pluralize(plural_word, 1, false));
+        }
+    }
+
+    #[test]
+    fn can_detect_plural_and_singular() {
+        for [singular_word, plural_word] in BASIC_TESTS {
+            assert!(is_plural(plural_word));
+            assert!(is_singular(singular_word));
+
+            // Uncountable words are both.
+            if singular_word == plural_word {
+                assert!(is_plural(singular_word));
+                assert!(is_singular(plural_word));
+            } else {
+                assert!(!is_plural(singular_word));
+                assert!(!is_singular(plural_word));
+            }
+        }
+    }
+
+    #[test]
+    fn instances_do_not_share_rules() {
+        let mut custom = Pluralizer::new();
+        let default = Pluralizer::new();
+
+        custom.add_irregular_rule("cow".to_string(), "kine".to_string());
+        custom.add_uncountable_rule(UncountableRule::String("house".to_string()));
+
+        assert_eq!(custom.pluralize("cow", 2, true), "2 kine");
+        assert_eq!(custom.plural("house"), "house");
+        assert_eq!(default.plural("cow"), "cows");
+        assert_eq!(default.plural("house"), "houses");
+        assert_eq!(plural("house"), "houses");
+    }
+
+    #[test]
+    fn can_reset_rules() {
+        let _shared = lock_shared_rules();
+
+        add_irregular_rule("cow".to_string(), "kine".to_string());
+        assert_eq!(plural("cow"), "kine");
+
+        reset_rules();
+        assert_eq!(plural("cow"), "cows");
+    }
+
+    #[test]
+    fn can_remove_irregular_rules() {
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer.add_irregular_rule("person".to_string(), "persons".to_string());
+        assert_eq!(pluralizer.plural("person"), "persons");
+
+        assert!(pluralizer.remove_irregular_rule("person"));
+        assert_eq!(pluralizer.plural("person"), "people");
+        assert_eq!(pluralizer.singular("persons"), "person");
+        assert_eq!(pluralizer.singular("people"), "person");
+
+        assert!(!pluralizer.remove_irregular_rule("person"));
+    }
+
+    #[test]
+    fn borrows_unchanged_words() {
+        use std::borrow::Cow;
+
+        assert!(matches!(pluralize_cow("sheep", 2), Cow::Borrowed("sheep")));
+        assert!(matches!(
+            pluralize_cow("houses", 2),
+            Cow::Borrowed("houses")
+        ));
+        assert!(matches!(pluralize_cow("house", 1), Cow::Borrowed("house")));
+        assert!(matches!(pluralize_cow("house", 2), Cow::Owned(ref s) if s == "houses"));
+        assert!(matches!(pluralize_cow("people", 1), Cow::Owned(ref s) if s == "person"));
+    }
+
+    #[test]
+    fn can_use_extension_trait() {
+        use crate::prelude::*;
+
+        assert_eq!("house".to_plural(), "houses");
+        assert_eq!("houses".to_singular(), "house");
+        assert_eq!(String::from("house").pluralize(2), "houses");
+        assert_eq!(String::from("houses").pluralize(1), "house");
+    }
+
+    #[test]
+    fn can_pluralize_floating_point_counts() {
+        assert_eq!(pluralize_f64("liter", 0.5, true), "0.5 liters");
+        assert_eq!(pluralize_f64("liters", 1.0, true), "1 liter");
+        assert_eq!(pluralize_f64("liter", 1.5, true), "1.5 liters");
+        assert_eq!(pluralize_f64("liter", 2.0, true), "2 liters");
+        assert_eq!(pluralize_f64("liter", 0.9999, false), "liters");
+        assert_eq!(pluralize_f64("liter", f64::NAN, false), "liters");
+    }
+
+    #[test]
+    fn can_pluralize_negative_counts() {
+        assert_eq!(pluralize("degree", -1, true), "-1 degree");
+        assert_eq!(pluralize("degree", -2, true), "-2 degrees");
+        assert_eq!(
+            pluralize("degree", isize::MIN, true),
+            format!("{} degrees", isize::MIN)
+        );
+        assert_eq!(pluralize_f64("degrees", -1.0, false), "degree");
+    }
+
+    #[test]
+    fn can_ordinalize() {
+        let tests = [
+            (0, "0th"),
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (102, "102nd"),
+            (111, "111th"),
+            (113, "113th"),
+            (-1, "-1st"),
+            (-12, "-12th"),
+            (-23, "-23rd"),
+        ];
+
+        for (n, ordinal) in tests {
+            assert_eq!(ordinalize(n), ordinal);
+        }
+
+        assert_eq!(ordinalize_word("house", 3), "3rd house");
+        assert_eq!(ordinalize_word("visitors", 21), "21st visitor");
+    }
+
+    #[test]
+    fn can_spell_out_counts() {
+        let tests = [
+            (0, "zero houses"),
+            (1, "one house"),
+            (2, "two houses"),
+            (13, "thirteen houses"),
+            (21, "twenty-one houses"),
+            (40, "forty houses"),
+            (100, "one hundred houses"),
+            (115, "one hundred fifteen houses"),
+            (1_000, "one thousand houses"),
+            (1_001, "one thousand one houses"),
+            (
+                2_345_678,
+                "two million three hundred forty-five thousand six hundred seventy-eight houses",
+            ),
+            (-1, "minus one house"),
+            (-22, "minus twenty-two houses"),
+        ];
+
+        for (count, words) in tests {
+            assert_eq!(pluralize_words("house", count), words);
+        }
+
+        assert!(pluralize_words("house", isize::MIN).starts_with("minus "));
+    }
+
+    #[test]
+    fn can_replace_zero_counts() {
+        assert_eq!(pluralize_with_zero("house", 0, "no"), "no houses");
+        assert_eq!(pluralize_with_zero("houses", 0, "no"), "no houses");
+        assert_eq!(pluralize_with_zero("house", 1, "no"), "1 house");
+        assert_eq!(pluralize_with_zero("house", 3, "no"), "3 houses");
+    }
+
+    #[test]
+    fn can_group_count_digits() {
+        assert_eq!(pluralize_grouped("house", 1, true), "1 house");
+        assert_eq!(pluralize_grouped("house", 999, true), "999 houses");
+        assert_eq!(pluralize_grouped("house", 1000, true), "1,000 houses");
+        assert_eq!(
+            pluralize_grouped("house", 1000000, true),
+            "1,000,000 houses"
+        );
+        assert_eq!(pluralize_grouped("house", -12345, true), "-12,345 houses");
+        assert_eq!(pluralize_grouped("house", 1000, false), "houses");
+        assert_eq!(
+            pluralize_grouped_by("house", 1234567, true, '.'),
+            "1.234.567 houses"
+        );
+    }
+
+    #[test]
+    fn rejects_placements_with_missing_groups() {
+        let mut pluralizer = Pluralizer::new();
+        let rule = Regex::new("(?i)(vert)(ex)$").unwrap();
+
+        assert_eq!(
+            pluralizer.add_plural_rule(rule.clone(), "$3ices".to_string()),
+            Err(RuleError::MissingCaptureGroup {
+                group: 3,
+                groups: 2
+            })
+        );
+        assert_eq!(
+            pluralizer.add_singular_rule(rule.clone(), "$3".to_string()),
+            Err(RuleError::MissingCaptureGroup {
+                group: 3,
+                groups: 2
+            })
+        );
+        assert_eq!(pluralizer.plural("vertex"), "vertices");
+
+        assert_eq!(
+            pluralizer.add_plural_rule(rule, "$1ixes".to_string()),
+            Ok(())
+        );
+        assert_eq!(pluralizer.plural("vertex"), "vertixes");
+    }
+
+    #[test]
+    fn keeps_literal_dollar_signs() {
+        assert_eq!(plural("price$"), "price$s");
+        assert_eq!(plural("$x"), "$xes");
+        assert_eq!(plural("$house"), "$houses");
+        assert_eq!(singular("$houses"), "$house");
+    }
+
+    #[test]
+    fn preserves_surrounding_whitespace_and_punctuation() {
+        assert_eq!(plural("  house  "), "  houses  ");
+        assert_eq!(plural("house."), "houses.");
+        assert_eq!(plural("house,"), "houses,");
+        assert_eq!(plural("house!"), "houses!");
+        assert_eq!(plural("(person)"), "(people)");
+        assert_eq!(singular(" Houses?! "), " House?! ");
+        assert_eq!(plural(" ... "), " ... ");
+    }
+
+    #[test]
+    fn can_pluralize_phrases() {
+        let tests = [
+            ["box of chocolates", "boxes of chocolates"],
+            ["attorney general", "attorneys general"],
+            ["court martial", "courts martial"],
+            ["cup of tea", "cups of tea"],
+            ["chocolate box", "chocolate boxes"],
+            ["house", "houses"],
+        ];
+
+        for [singular, plural] in tests {
+            assert_eq!(pluralize_phrase(singular, 2), plural);
+            assert_eq!(pluralize_phrase(plural, 1), singular);
+        }
+
+        assert_eq!(
+            pluralize_phrase("  box  of chocolates ", 2),
+            "  boxes  of chocolates "
+        );
+        assert_eq!(pluralize_phrase("", 2), "");
+
+        let mut pluralizer = Pluralizer::new();
+        pluralizer.add_preposition("sans".to_string());
+        assert_eq!(
+            pluralizer.pluralize_phrase("cat sans hat", 2),
+            "cats sans hat"
+        );
+    }
+
+    #[test]
+    fn can_pluralize_hyphenated_compounds() {
+        let tests = [
+            ["mother-in-law", "mothers-in-law"],
+            ["son-in-law", "sons-in-law"],
+            ["passer-by", "passers-by"],
+            ["court-martial", "courts-martial"],
+            ["editor-in-chief", "editors-in-chief"],
+            ["lady-in-waiting", "ladies-in-waiting"],
+            ["man-of-war", "men-of-war"],
+            ["Attorney-General", "Attorneys-General"],
+            ["check-in", "check-ins"],
+            ["well-being", "well-beings"],
+        ];
+
+        for [singular, plural] in tests {
+            assert_eq!(pluralize(singular, 2, false), plural);
+            assert_eq!(pluralize(plural, 1, false), singular);
+            assert_eq!(pluralize(plural, 2, false), plural);
+        }
+    }
+
+    #[test]
+    fn can_pluralize_possessives() {
+        let tests = [
+            ["dog's", "dogs'"],
+            ["cat's", "cats'"],
+            ["man's", "men's"],
+            ["child's", "children's"],
+            ["person's", "people's"],
+            ["Person's", "People's"],
+            ["CAT'S", "CATS'"],
+            ["wife’s", "wives’"],
+        ];
+
+        for [singular, plural] in tests {
+            assert_eq!(pluralize(singular, 2, false), plural);
+            assert_eq!(pluralize(plural, 1, false), singular);
+            assert_eq!(pluralize(plural, 2, false), plural);
+            assert_eq!(pluralize(singular, 1, false), singular);
+        }
+
+        assert_eq!(plural("mother-in-law's"), "mothers-in-law's");
+    }
+
+    #[test]
+    fn empty_instances_have_no_rules() {
+        let mut pluralizer = Pluralizer::empty();
+
+        assert_eq!(pluralizer.plural("house"), "house");
+        assert_eq!(pluralizer.plural("person"), "person");
+        assert_eq!(pluralizer.singular("people"), "people");
+
+        pluralizer.add_irregular_rule("person".to_string(), "people".to_string());
+        pluralizer
+            .add_plural_rule(Regex::new("(?i)$").unwrap(), "s".to_string())
+            .unwrap();
+
+        assert_eq!(pluralizer.plural("person"), "people");
+        assert_eq!(pluralizer.plural("house"), "houses");
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn cached_results_follow_rule_changes() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.plural("cactus"), "cacti");
+        assert_eq!(pluralizer.plural("cactus"), "cacti");
+
+        pluralizer.add_irregular_rule("cactus".to_string(), "cactuses".to_string());
+        assert_eq!(pluralizer.plural("cactus"), "cactuses");
+
+        pluralizer.set_cache_capacity(1);
+        assert_eq!(pluralizer.plural("cactus"), "cactuses");
+        assert_eq!(pluralizer.singular("cactuses"), "cactus");
+        assert_eq!(pluralizer.plural("cactus"), "cactuses");
+
+        pluralizer.set_cache_capacity(0);
+        assert_eq!(pluralizer.plural("CACTUS"), "CACTUSES");
+    }
+
+    #[test]
+    fn irregular_rule_queries() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(
+            pluralizer.get_irregular_plural("Echo"),
+            Some("echoes".to_string())
+        );
+        assert_eq!(
+            pluralizer.get_irregular_singular("TORNADOES"),
+            Some("tornado".to_string())
+        );
+        assert_eq!(pluralizer.get_irregular_plural("house"), None);
+
+        pluralizer.add_irregular_rule("octopus".to_string(), "octopodes".to_string());
+        assert_eq!(
+            pluralizer.get_irregular_singular("octopodes"),
+            Some("octopus".to_string())
+        );
+
+        let rules = pluralizer.irregular_rules();
+        assert!(rules.contains(&("octopus".to_string(), "octopodes".to_string())));
+        assert!(rules.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn uncountable_queries() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert!(pluralizer.uncountable_words().contains(&"rice".to_string()));
+        assert!(pluralizer.is_uncountable("Sheep"));
+        assert!(pluralizer.is_uncountable("reindeer"));
+        assert!(!pluralizer.is_uncountable("house"));
+
+        pluralizer.add_uncountable_rule(UncountableRule::String("Cash".to_string()));
+        pluralizer.add_uncountable_rule(UncountableRule::Regex(Regex::new("(?i)ware$").unwrap()));
+
+        assert!(pluralizer.uncountable_words().contains(&"cash".to_string()));
+        assert!(pluralizer.is_uncountable("CASH"));
+        assert!(pluralizer.is_uncountable("software"));
+    }
+
+    #[test]
+    fn try_add_rules_from_patterns() {
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer
+            .try_add_plural_rule("(?i)(ox)$", "$1en".to_string())
+            .unwrap();
+        pluralizer
+            .try_add_singular_rule("(?i)(ox)en$", "$1".to_string())
+            .unwrap();
+        pluralizer.try_add_uncountable_rule("(?i)ware$").unwrap();
+
+        assert_eq!(pluralizer.plural("ox"), "oxen");
+        assert_eq!(pluralizer.singular("oxen"), "ox");
+        assert_eq!(pluralizer.plural("middleware"), "middleware");
+
+        assert!(matches!(
+            pluralizer.try_add_plural_rule("(?i)(ox$", "$1en".to_string()),
+            Err(RuleError::InvalidRegex(_))
+        ));
+        assert!(matches!(
+            pluralizer.try_add_uncountable_rule("ware)"),
+            Err(RuleError::InvalidRegex(_))
+        ));
+        assert_eq!(
+            pluralizer.try_add_singular_rule("(?i)ox$", "$1".to_string()),
+            Err(RuleError::MissingCaptureGroup {
+                group: 1,
+                groups: 0
+            })
+        );
+    }
+
+    #[test]
+    fn scoped_rules_are_restored() {
+        let _shared = lock_shared_rules();
+
+        {
+            let scope = scoped();
+            scope.add_irregular_rule("blorp".to_string(), "blorpen".to_string());
+            scope.add_uncountable_rule(UncountableRule::String("glorp".to_string()));
+
+            assert_eq!(plural("blorp"), "blorpen");
+            assert_eq!(plural("glorp"), "glorp");
+        }
+
+        assert_eq!(plural("blorp"), "blorps");
+        assert_eq!(plural("glorp"), "glorps");
+
+        let result = std::panic::catch_unwind(|| {
+            let scope = scoped();
+            scope.add_irregular_rule("blorp".to_string(), "blorpen".to_string());
+            panic!("unwinding out of the scope");
+        });
+
+        assert!(result.is_err());
+        assert_eq!(plural("blorp"), "blorps");
+    }
+
+    #[test]
+    fn snapshots_restore_every_collection() {
+        let _shared = lock_shared_rules();
+
+        let base = snapshot();
+
+        add_irregular_rule("snorp".to_string(), "snorpen".to_string());
+        add_uncountable_rule(UncountableRule::String("zorp".to_string()));
+        add_plural_rule(Regex::new("(?i)(flib)$").unwrap(), "$1ii".to_string()).unwrap();
+        assert_eq!(plural("snorp"), "snorpen");
+        assert_eq!(plural("zorp"), "zorp");
+        assert_eq!(plural("flib"), "flibii");
+
+        restore(base.clone());
+        assert_eq!(plural("snorp"), "snorps");
+        assert_eq!(plural("zorp"), "zorps");
+        assert_eq!(plural("flib"), "flibs");
+
+        add_irregular_rule("snorp".to_string(), "snorpen".to_string());
+        restore(base);
+        assert_eq!(plural("snorp"), "snorps");
+    }
+
+    #[test]
+    fn can_convert_batches() {
+        assert_eq!(
+            pluralize_all(&["House", "child"], 2),
+            ["Houses", "children"]
+        );
+        assert_eq!(
+            pluralize_all(&["Houses", "children"], 1),
+            ["House", "child"]
+        );
+        assert_eq!(plural_all(&["user", "person"]), ["users", "people"]);
+        assert_eq!(singular_all(&["users", "people"]), ["user", "person"]);
+        assert!(plural_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn preserves_mixed_case_prefixes() {
+        let cases = [
+            ["userId", "userIds"],
+            ["HTTPRequest", "HTTPRequests"],
+            ["snake_case", "snake_cases"],
+            ["USER_ID", "USER_IDS"],
+            ["userIndex", "userIndices"],
+            ["myMatrix", "myMatrices"],
+            ["XMLQuery", "XMLQueries"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(plural(singular_word), plural_word);
+            assert_eq!(singular(plural_word), singular_word);
+        }
+    }
+
+    #[test]
+    fn restores_title_case_of_multibyte_words() {
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer.add_irregular_rule("éclair".to_string(), "éclairs".to_string());
+        pluralizer.add_irregular_rule("яблоко".to_string(), "яблоки".to_string());
+        pluralizer.add_irregular_rule("straße".to_string(), "straßen".to_string());
+
+        assert_eq!(pluralizer.plural("Éclair"), "Éclairs");
+        assert_eq!(pluralizer.plural("Яблоко"), "Яблоки");
+        assert_eq!(pluralizer.singular("Яблоки"), "Яблоко");
+        assert_eq!(pluralizer.plural("Straße"), "Straßen");
+        assert_eq!(pluralizer.plural("ÉCLAIR"), "ÉCLAIRS");
+        assert_eq!(pluralizer.plural("Tooth"), "Teeth");
+    }
+
+    #[test]
+    fn can_use_spanish_rules() {
+        let spanish = locales::es::pluralizer();
+        let cases = [
+            ["casa", "casas"],
+            ["luz", "luces"],
+            ["pez", "peces"],
+            ["rey", "reyes"],
+            ["mes", "meses"],
+            ["papel", "papeles"],
+            ["flor", "flores"],
+            ["pared", "paredes"],
+            ["canción", "canciones"],
+            ["autobús", "autobuses"],
+            ["inglés", "ingleses"],
+            ["lunes", "lunes"],
+            ["Casa", "Casas"],
+            ["LUZ", "LUCES"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(spanish.plural(singular_word), plural_word);
+            assert_eq!(spanish.singular(plural_word), singular_word);
+        }
+
+        assert_eq!(spanish.pluralize("luz", 2, true), "2 luces");
+        assert_eq!(
+            spanish.pluralize_phrase("casa de campo", 2),
+            "casas de campo"
+        );
+        assert_eq!(plural("casa"), "casas");
+    }
+
+    #[test]
+    fn can_use_german_rules() {
+        let german = locales::de::pluralizer();
+        let cases = [
+            ["Auto", "Autos"],
+            ["Kind", "Kinder"],
+            ["Frau", "Frauen"],
+            ["Apfel", "Äpfel"],
+            ["Tag", "Tage"],
+            ["Blume", "Blumen"],
+            ["Straße", "Straßen"],
+            ["Lehrer", "Lehrer"],
+            ["Mädchen", "Mädchen"],
+            ["Zeitung", "Zeitungen"],
+            ["Lehrerin", "Lehrerinnen"],
+            ["Ergebnis", "Ergebnisse"],
+            ["Obst", "Obst"],
+            ["APFEL", "ÄPFEL"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(german.plural(singular_word), plural_word);
+            assert_eq!(german.singular(plural_word), singular_word);
+        }
+
+        assert_eq!(german.pluralize_phrase("Haus am See", 2), "Häuser am See");
+        assert_eq!(plural("Kind"), "Kinds");
+    }
+
+    #[test]
+    fn can_find_plural_categories() {
+        use PluralCategory::*;
+
+        let cases: &[(&str, &[(f64, PluralCategory)])] = &[
+            (
+                "en",
+                &[
+                    (0.0, Other),
+                    (1.0, One),
+                    (-1.0, One),
+                    (1.5, Other),
+                    (2.0, Other),
+                ],
+            ),
+            (
+                "pl",
+                &[
+                    (1.0, One),
+                    (2.0, Few),
+                    (5.0, Many),
+                    (12.0, Many),
+                    (22.0, Few),
+                    (1.5, Other),
+                ],
+            ),
+            (
+                "ru",
+                &[
+                    (1.0, One),
+                    (11.0, Many),
+                    (21.0, One),
+                    (3.0, Few),
+                    (25.0, Many),
+                    (1.5, Other),
+                ],
+            ),
+            (
+                "ar",
+                &[
+                    (0.0, Zero),
+                    (1.0, One),
+                    (2.0, Two),
+                    (3.0, Few),
+                    (11.0, Many),
+                    (100.0, Other),
+                ],
+            ),
+            (
+                "fr",
+                &[(0.0, One), (1.5, One), (2.0, Other), (1_000_000.0, Many)],
+            ),
+            ("en-US", &[(1.0, One), (2.0, Other)]),
+            ("xx", &[(1.0, Other)]),
+        ];
+
+        for (lang, counts) in cases {
+            for (count, category) in *counts {
+                assert_eq!(
+                    plural_category(*count, lang),
+                    *category,
+                    "{} in {}",
+                    count,
+                    lang
+                );
+            }
+        }
+
+        assert_eq!(plural_category(f64::NAN, "en"), Other);
+    }
+
+    #[test]
+    fn can_prefer_classical_plurals() {
+        let mut pluralizer = Pluralizer::new();
+        let cases = [
+            ["octopus", "octopuses", "octopi"],
+            ["formula", "formulas", "formulae"],
+            ["stadium", "stadiums", "stadia"],
+            ["Vortex", "Vortexes", "Vortices"],
+            ["bureau", "bureaus", "bureaux"],
+        ];
+
+        for [word, modern, _] in cases {
+            assert_eq!(pluralizer.plural(word), modern);
+        }
+
+        pluralizer.set_classical_mode(true);
+
+        for [word, modern, classical] in cases {
+            assert_eq!(pluralizer.plural(word), classical);
+            assert_eq!(pluralizer.singular(classical), word);
+            assert_eq!(pluralizer.singular(modern), word);
+        }
+
+        pluralizer.add_irregular_rule("octopus".to_string(), "octopodes".to_string());
+        assert_eq!(pluralizer.plural("octopus"), "octopodes");
+
+        pluralizer.set_classical_mode(false);
+        assert_eq!(pluralizer.plural("formula"), "formulas");
+    }
+
+    #[test]
+    fn can_load_and_export_configs() {
+        let mut pluralizer = Pluralizer::new();
+        let config = RuleConfig {
+            irregular: vec![("cow".to_string(), "kine".to_string())],
+            plural: vec![RegexRule {
+                pattern: "(?i)^(ox)$".to_string(),
+                placement: "$1en".to_string(),
+            }],
+            singular: vec![RegexRule {
+                pattern: "(?i)^(ox)en$".to_string(),
+                placement: "$1".to_string(),
+            }],
+            uncountable: vec!["cash".to_string()],
+            uncountable_exact: vec!["Flox".to_string()],
+            uncountable_regex: vec!["(?i)ware$".to_string()],
+        };
+
+        pluralizer.load_config(&config).unwrap();
+        assert_eq!(pluralizer.plural("cow"), "kine");
+        assert_eq!(pluralizer.plural("ox"), "oxen");
+        assert_eq!(pluralizer.singular("oxen"), "ox");
+        assert_eq!(pluralizer.plural("cash"), "cash");
+        assert_eq!(pluralizer.plural("middleware"), "middleware");
+        assert_eq!(pluralizer.plural("Flox"), "Flox");
+        assert_eq!(pluralizer.plural("flox"), "floxes");
+
+        let mut copy = Pluralizer::empty();
+        copy.load_config(&pluralizer.export_config()).unwrap();
+        assert_eq!(copy.export_config(), pluralizer.export_config());
+
+        for [singular_word, plural_word] in BASIC_TESTS.iter().chain(PLURAL_TESTS) {
+            assert_eq!(copy.plural(singular_word), pluralizer.plural(singular_word));
+            assert_eq!(copy.singular(plural_word), pluralizer.singular(plural_word));
+        }
+
+        let invalid = RuleConfig {
+            irregular: vec![("goose".to_string(), "gooses".to_string())],
+            singular: vec![RegexRule {
+                pattern: "(?i)(ox$".to_string(),
+                placement: "$1".to_string(),
+            }],
+            ..RuleConfig::default()
+        };
+
+        assert!(matches!(
+            pluralizer.load_config(&invalid),
+            Err(RuleError::InvalidRegex(_))
+        ));
+        assert_eq!(pluralizer.plural("goose"), "geese");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn can_deserialize_configs() {
+        let config: RuleConfig = serde_json::from_str(
+            r#"{
+                "irregular": [["cow", "kine"]],
+                "plural": [{ "pattern": "(?i)(ox)$", "placement": "$1en" }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.irregular, [("cow".to_string(), "kine".to_string())]);
+        assert!(config.uncountable.is_empty());
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<RuleConfig>(&json).unwrap(), config);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn can_load_rules_from_json() {
+        let dir = std::env::temp_dir().join(format!("pluralizer-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let valid = dir.join("valid.json");
+        std::fs::write(
+            &valid,
+            r#"{
+                "irregular": [["cow", "kine"]],
+                "uncountable": ["cash"]
+            }"#,
+        )
+        .unwrap();
+
+        let invalid = dir.join("invalid.json");
+        std::fs::write(
+            &invalid,
+            r#"{
+                "irregular": [["goose", "gooses"]],
+                "plural": [
+                    { "pattern": "(?i)(ox)$", "placement": "$1en" },
+                    { "pattern": "(?i)(ox$", "placement": "$1en" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let broken = dir.join("broken.json");
+        std::fs::write(&broken, "{ \"irregular\": ").unwrap();
+
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer.load_rules_from_json(&valid).unwrap();
+        assert_eq!(pluralizer.plural("cow"), "kine");
+        assert_eq!(pluralizer.plural("cash"), "cash");
+
+        match pluralizer.load_rules_from_json(&invalid) {
+            Err(LoadError::InvalidRule {
+                pattern,
+                line,
+                error: RuleError::InvalidRegex(_),
+            }) => {
+                assert_eq!(pattern, "(?i)(ox$");
+                assert_eq!(line, Some(5));
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(pluralizer.plural("goose"), "geese");
+
+        assert!(matches!(
+            pluralizer.load_rules_from_json(&broken),
+            Err(LoadError::Parse(_))
+        ));
+        assert!(matches!(
+            pluralizer.load_rules_from_json(&dir.join("missing.json")),
+            Err(LoadError::Io(_))
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn can_add_rules_in_bulk() {
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer.add_irregular_rules([
+            ("cow".to_string(), "kine".to_string()),
+            ("brother".to_string(), "brethren".to_string()),
+        ]);
+        pluralizer.add_uncountable_rules(vec!["Cash".to_string(), "feedback".to_string()]);
+
+        assert_eq!(pluralizer.plural("cow"), "kine");
+        assert_eq!(pluralizer.singular("brethren"), "brother");
+        assert_eq!(pluralizer.plural("cash"), "cash");
+        assert_eq!(pluralizer.plural("Feedback"), "Feedback");
+    }
+
+    #[test]
+    fn can_use_custom_count_separators() {
+        assert_eq!(
+            pluralize_with_separator("House", 2, "\u{00A0}"),
+            "2\u{00A0}Houses"
+        );
+        assert_eq!(pluralize_with_separator("Houses", 1, ""), "1House");
+        assert_eq!(pluralize_with_separator("box", -3, " x "), "-3 x boxes");
+        assert_eq!(
+            pluralize("House", 2, true),
+            pluralize_with_separator("House", 2, " ")
+        );
+    }
+
+    #[test]
+    fn can_place_counts_after_words() {
+        assert_eq!(pluralize_count_after("House", 5), "Houses 5");
+        assert_eq!(pluralize_count_after("Houses", 1), "House 1");
+        assert_eq!(pluralize_count_after("child", 0), "children 0");
+    }
+
+    #[test]
+    fn can_write_parenthesized_plurals() {
+        assert_eq!(pluralize_paren("item"), "item(s)");
+        assert_eq!(pluralize_paren("box"), "box(es)");
+        assert_eq!(pluralize_paren("boxes"), "box(es)");
+        assert_eq!(pluralize_paren("Child"), "Child(ren)");
+        assert_eq!(pluralize_paren("city"), "city/cities");
+        assert_eq!(pluralize_paren("person"), "person/people");
+        assert_eq!(pluralize_paren("sheep"), "sheep");
+    }
+
+    #[test]
+    fn keeps_acronyms_upper_cased() {
+        let mut pluralizer = Pluralizer::new();
+        let cases = [
+            ["URL", "URLs"],
+            ["ID", "IDs"],
+            ["CPU", "CPUs"],
+            ["MP3", "MP3s"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(pluralizer.plural(singular_word), plural_word);
+            assert_eq!(pluralizer.singular(plural_word), singular_word);
+        }
+
+        assert_eq!(pluralizer.plural("BUS"), "BUSES");
+        assert_eq!(pluralizer.plural("HOUSE"), "HOUSES");
+
+        pluralizer.set_acronym_mode(false);
+        assert_eq!(pluralizer.plural("URL"), "URLS");
+    }
+
+    #[test]
+    fn added_regex_rules_are_not_irregulars() {
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer
+            .add_plural_rule(Regex::new("(?i)(quiz)$").unwrap(), "$1zes".to_string())
+            .unwrap();
+        pluralizer
+            .add_singular_rule(Regex::new("(?i)(quiz)zes$").unwrap(), "$1".to_string())
+            .unwrap();
+
+        // The rules apply to every matching word, not just the pattern as a literal word.
+        assert_eq!(pluralizer.plural("popquiz"), "popquizzes");
+        assert_eq!(pluralizer.singular("popquizzes"), "popquiz");
+        assert_eq!(pluralizer.get_irregular_plural("(?i)(quiz)$"), None);
+    }
+
+    #[test]
+    fn can_pluralize_conditionally() {
+        assert_eq!(pluralize_if("House", true), "Houses");
+        assert_eq!(pluralize_if("Houses", true), "Houses");
+        assert_eq!(pluralize_if("Houses", false), "House");
+        assert_eq!(pluralize_if("House", false), "House");
+    }
+
+    #[test]
+    fn can_override_singular_counts() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.pluralize("House", 0, true), "0 Houses");
+
+        pluralizer.set_singular_predicate(|n| n == 0 || n == 1);
+        assert_eq!(pluralizer.pluralize("House", 0, true), "0 House");
+        assert_eq!(pluralizer.pluralize("House", 1, true), "1 House");
+        assert_eq!(pluralizer.pluralize("House", -1, true), "-1 Houses");
+        assert_eq!(pluralizer.pluralize_all(&["Houses"], 0), ["House"]);
+    }
+
+    #[test]
+    fn can_force_result_casing() {
+        assert_eq!(pluralize_cased("house", 2, CaseMode::Title), "Houses");
+        assert_eq!(pluralize_cased("HOUSES", 1, CaseMode::Title), "House");
+        assert_eq!(pluralize_cased("House", 2, CaseMode::Lower), "houses");
+        assert_eq!(pluralize_cased("House", 2, CaseMode::Upper), "HOUSES");
+        assert_eq!(
+            pluralize_cased("hOuse", 2, CaseMode::Preserve),
+            plural("hOuse")
+        );
+        assert_eq!(pluralize_cased("élan", 2, CaseMode::Title), "Élans");
+        assert_eq!(pluralize_cased("ÿacht", 2, CaseMode::Title), "Ÿachts");
+        assert_eq!(pluralize_cased("", 2, CaseMode::Title), "");
+    }
+
+    #[test]
+    fn can_limit_the_number_of_rules() {
+        let mut pluralizer = Pluralizer::empty();
+
+        assert_eq!(pluralizer.rule_count(), 0);
+        pluralizer.set_max_rules(Some(2));
+
+        pluralizer
+            .try_add_plural_rule("(?i)$", "s".to_string())
+            .unwrap();
+        pluralizer
+            .try_add_singular_rule("(?i)s$", "".to_string())
+            .unwrap();
+        assert_eq!(pluralizer.rule_count(), 2);
+
+        assert_eq!(
+            pluralizer.try_add_plural_rule("(?i)(ox)$", "$1en".to_string()),
+            Err(RuleError::TooManyRules { max: 2 })
+        );
+
+        let config = RuleConfig {
+            plural: vec![RegexRule {
+                pattern: "(?i)(ox)$".to_string(),
+                placement: "$1en".to_string(),
+            }],
+            ..RuleConfig::default()
+        };
+
+        assert_eq!(
+            pluralizer.load_config(&config),
+            Err(RuleError::TooManyRules { max: 2 })
+        );
+
+        pluralizer.set_max_rules(None);
+        pluralizer.load_config(&config).unwrap();
+        assert_eq!(pluralizer.rule_count(), 3);
+        assert_eq!(pluralizer.plural("ox"), "oxen");
+    }
+
+    #[test]
+    fn skips_duplicate_rules() {
+        let mut pluralizer = Pluralizer::new();
+        let rules = pluralizer.rule_count();
+        let words = pluralizer.uncountable_words().len();
+
+        for _ in 0..2 {
+            pluralizer
+                .try_add_plural_rule("(?i)(flox)$", "$1en".to_string())
+                .unwrap();
+            pluralizer
+                .try_add_singular_rule("(?i)(flox)en$", "$1".to_string())
+                .unwrap();
+            pluralizer.try_add_uncountable_rule("(?i)ware$").unwrap();
+            pluralizer.add_uncountable_rule(UncountableRule::String("Cash2".to_string()));
+            pluralizer.add_uncountable_rules(["cash2".to_string()]);
+        }
+
+        assert_eq!(pluralizer.rule_count(), rules + 4);
+        assert_eq!(pluralizer.uncountable_words().len(), words + 1);
+
+        // The same pattern with another placement is a different rule.
+        pluralizer
+            .try_add_plural_rule("(?i)(flox)$", "$1es".to_string())
+            .unwrap();
+        assert_eq!(pluralizer.rule_count(), rules + 5);
+        assert_eq!(pluralizer.plural("flox"), "floxes");
+
+        pluralizer.set_max_rules(Some(rules + 5));
+        assert!(pluralizer
+            .try_add_plural_rule("(?i)(flox)$", "$1es".to_string())
+            .is_ok());
+    }
+
+    #[test]
+    fn can_choose_rule_priority() {
+        let mut pluralizer = Pluralizer::new();
+        let fallback = Regex::new("(?i)us$").unwrap();
+
+        pluralizer
+            .add_plural_rule_with_priority(fallback.clone(), "uses".to_string(), Priority::First)
+            .unwrap();
+        assert_eq!(pluralizer.plural("cactus"), "cacti");
+        assert_eq!(pluralizer.plural("campus"), "campuses");
+
+        pluralizer
+            .add_plural_rule_with_priority(fallback, "odes".to_string(), Priority::Last)
+            .unwrap();
+        assert_eq!(pluralizer.plural("cactus"), "cactodes");
+
+        let mut empty = Pluralizer::empty();
+
+        empty
+            .add_singular_rule_with_priority(
+                Regex::new("(?i)s$").unwrap(),
+                "".to_string(),
+                Priority::Last,
+            )
+            .unwrap();
+        empty
+            .add_singular_rule_with_priority(
+                Regex::new("(?i)ies$").unwrap(),
+                "y".to_string(),
+                Priority::First,
+            )
+            .unwrap();
+        assert_eq!(empty.singular("cities"), "citie");
+    }
+
+    #[test]
+    fn invariant_words_are_not_uncountable() {
+        let mut pluralizer = Pluralizer::new();
+
+        pluralizer.add_invariant_rule("Corps");
+        pluralizer.add_invariant_rule("moose");
+        pluralizer.add_uncountable_rule(UncountableRule::String("furniture".to_string()));
+
+        assert_eq!(pluralizer.pluralize("corps", 2, true), "2 corps");
+        assert_eq!(pluralizer.pluralize("Corps", 1, true), "1 Corps");
+        assert_eq!(pluralizer.plural("moose"), "moose");
+        assert_eq!(pluralizer.singular("moose"), "moose");
+        assert!(pluralizer.is_plural("moose") && pluralizer.is_singular("moose"));
+
+        assert!(!pluralizer.is_uncountable("kudzu"));
+        pluralizer.add_invariant_rule("kudzu");
+        assert_eq!(pluralizer.plural("kudzu"), "kudzu");
+        assert!(!pluralizer.is_uncountable("kudzu"));
+        assert!(pluralizer.is_uncountable("furniture"));
+        assert_eq!(
+            pluralizer.get_irregular_plural("kudzu"),
+            Some("kudzu".to_string())
+        );
+    }
+
+    #[test]
+    fn can_pluralize_selected_tokens() {
+        let nouns = ["box", "boxes", "child", "children", "mother-in-law's"];
+        let is_noun = |word: &str| nouns.contains(&word.to_lowercase().as_str());
+
+        assert_eq!(
+            pluralize_tokens(
+                "Put the box,  and the child's toy (child) away!",
+                2,
+                is_noun
+            ),
+            "Put the boxes,  and the child's toy (children) away!"
+        );
+        assert_eq!(
+            pluralize_tokens("\"Boxes\" and  children...", 1, is_noun),
+            "\"Box\" and  child..."
+        );
+        assert_eq!(
+            pluralize_tokens("my mother-in-law's car", 2, is_noun),
+            "my mothers-in-law's car"
+        );
+        assert_eq!(
+            pluralize_tokens("'box' - -box-", 2, is_noun),
+            "'boxes' - -boxes-"
+        );
+        assert_eq!(pluralize_tokens("", 2, is_noun), "");
+    }
+
+    #[test]
+    fn can_prefix_articles() {
+        assert_eq!(with_article("apple"), "an apple");
+        assert_eq!(with_article("house"), "a house");
+        assert_eq!(with_article("Hour"), "an Hour");
+        assert_eq!(with_article("honest mistake"), "an honest mistake");
+        assert_eq!(with_article("unicorn"), "a unicorn");
+        assert_eq!(with_article("university"), "a university");
+        assert_eq!(with_article("unit"), "a unit");
+        assert_eq!(with_article("umbrella"), "an umbrella");
+        assert_eq!(with_article("uterus"), "a uterus");
+        assert_eq!(with_article("utensil"), "a utensil");
+        assert_eq!(with_article("utter mess"), "an utter mess");
+        assert_eq!(with_article("one-way street"), "a one-way street");
+        assert_eq!(with_article("onerous task"), "an onerous task");
+        assert_eq!(with_article(&singular("eggs")), "an egg");
+
+        let _shared = lock_shared_rules();
+        add_article_rule("blorp", Article::An);
+        assert_eq!(with_article("Blorp"), "an Blorp");
+        add_article_rule("blorp", Article::A);
+        assert_eq!(with_article("blorp"), "a blorp");
+    }
+
+    #[test]
+    fn can_match_uncountable_words_case_sensitively() {
+        let mut pluralizer = Pluralizer::new();
+        pluralizer.add_uncountable_rule(UncountableRule::StringExact("Polish".to_string()));
+
+        assert_eq!(pluralizer.plural("Polish"), "Polish");
+        assert_eq!(pluralizer.singular("Polish"), "Polish");
+        assert!(pluralizer.is_uncountable("Polish"));
+
+        assert_eq!(pluralizer.plural("polish"), "polishes");
+        assert_eq!(pluralizer.plural("POLISH"), "POLISHES");
+        assert!(!pluralizer.is_uncountable("polish"));
+        assert_eq!(pluralizer.pluralize("Polish", 2, true), "2 Polish");
+        assert_eq!(pluralizer.pluralize("polish", 2, true), "2 polishes");
+    }
+
+    #[test]
+    fn can_explain_transformations() {
+        let mut pluralizer = Pluralizer::new();
+        let source = |t: Transformation| t.source;
+
+        assert_eq!(
+            pluralizer.explain_plural("goose"),
+            Transformation {
+                result: "geese".to_string(),
+                source: TransformSource::Irregular,
+            }
+        );
+        assert_eq!(
+            source(pluralizer.explain_plural("geese")),
+            TransformSource::Kept
+        );
+        assert_eq!(
+            source(pluralizer.explain_plural("rice")),
+            TransformSource::Uncountable
+        );
+        assert_eq!(
+            source(pluralizer.explain_plural("sheep")),
+            TransformSource::Uncountable
+        );
+        assert_eq!(
+            source(pluralizer.explain_singular("CPUs")),
+            TransformSource::Acronym
+        );
+        assert_eq!(
+            source(pluralizer.explain_plural("")),
+            TransformSource::Unchanged
+        );
+        assert_eq!(
+            pluralizer.explain_plural("(Fox's)"),
+            Transformation {
+                result: "(Foxes')".to_string(),
+                source: TransformSource::Rule {
+                    pattern: "(?i)(x|ch|ss|sh|z)$".to_string(),
+                    placement: "$1es".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            source(pluralizer.explain_plural("mother-in-law")),
+            source(pluralizer.explain_plural("mother"))
+        );
+
+        pluralizer
+            .try_add_plural_rule("(?i)orp$", "orpen".to_string())
+            .unwrap();
+        assert_eq!(
+            pluralizer.explain_plural("blorp"),
+            Transformation {
+                result: "blorpen".to_string(),
+                source: TransformSource::Rule {
+                    pattern: "(?i)orp$".to_string(),
+                    placement: "orpen".to_string(),
+                },
+            }
+        );
+
+        pluralizer.set_classical_mode(true);
+        assert_eq!(
+            source(pluralizer.explain_plural("formula")),
+            TransformSource::Classical
+        );
+    }
+
+    #[test]
+    fn can_tableize_and_classify() {
+        assert_eq!(tableize("BlogPost"), "blog_posts");
+        assert_eq!(tableize("Person"), "people");
+        assert_eq!(tableize("HTTPRequest"), "http_requests");
+        assert_eq!(tableize("OAuth2Client"), "o_auth2_clients");
+        assert_eq!(tableize("line_item"), "line_items");
+        assert_eq!(tableize(""), "");
+
+        assert_eq!(classify("blog_posts"), "BlogPost");
+        assert_eq!(classify("people"), "Person");
+        assert_eq!(classify("line_items"), "LineItem");
+        assert_eq!(classify("categories"), "Category");
+        assert_eq!(classify(""), "");
+
+        for name in ["BlogPost", "Person", "Category", "MouseTrap"] {
+            assert_eq!(classify(&tableize(name)), name);
+        }
+    }
+
+    #[test]
+    fn can_underscore_camelize_and_dasherize() {
+        assert_eq!(underscore("BlogPost"), "blog_post");
+        assert_eq!(underscore("blogPost"), "blog_post");
+        assert_eq!(underscore("HTTPServer"), "http_server");
+        assert_eq!(underscore("getHTTPResponseCode"), "get_http_response_code");
+        assert_eq!(underscore("ServerHTTP"), "server_http");
+        assert_eq!(underscore("ABC"), "abc");
+        assert_eq!(underscore("IPv6Address"), "i_pv6_address");
+        assert_eq!(underscore("Base64Encoder"), "base64_encoder");
+        assert_eq!(underscore("blog-post title"), "blog_post_title");
+        assert_eq!(underscore("blog_post"), "blog_post");
+        assert_eq!(underscore(""), "");
+
+        assert_eq!(camelize("blog_post"), "BlogPost");
+        assert_eq!(camelize("http_server"), "HttpServer");
+        assert_eq!(camelize("BlogPost"), "BlogPost");
+        assert_eq!(camelize("_private__field_"), "PrivateField");
+        assert_eq!(camelize(""), "");
+
+        assert_eq!(dasherize("blog_post"), "blog-post");
+        assert_eq!(dasherize(&underscore("HTTPServer")), "http-server");
+    }
+
+    #[cfg(feature = "thread-local")]
+    #[test]
+    fn keeps_rules_local_to_the_thread() {
+        std::thread::spawn(|| {
+            add_irregular_rule("snorp".to_string(), "snorpii".to_string());
+            assert_eq!(plural("snorp"), "snorpii");
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(plural("snorp"), "snorps");
+
+        add_irregular_rule("snorp".to_string(), "snorpen".to_string());
+        assert_eq!(plural("snorp"), "snorpen");
+        assert_eq!(plural("house"), "houses");
+
+        std::thread::spawn(|| assert_eq!(plural("snorp"), "snorps"))
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn can_pluralize_ranges() {
+        assert_eq!(pluralize_range("house", 2, 5), "2\u{2013}5 houses");
+        assert_eq!(pluralize_range("house", 1, 2), "1\u{2013}2 houses");
+        assert_eq!(pluralize_range("house", 0, 1), "0\u{2013}1 houses");
+        assert_eq!(pluralize_range("houses", 1, 1), "1 house");
+        assert_eq!(pluralize_range("house", 3, 3), "3 houses");
+        assert_eq!(pluralize_range("house", -1, 1), "-1\u{2013}1 house");
+    }
+
+    #[test]
+    fn can_classify_words() {
+        let mut pluralizer = Pluralizer::new();
+        pluralizer.add_invariant_rule("flox");
+
+        assert_eq!(pluralizer.classify_word("rice"), WordClass::Uncountable);
+        assert_eq!(pluralizer.classify_word("Sheep"), WordClass::Uncountable);
+        assert_eq!(pluralizer.classify_word("goose"), WordClass::Irregular);
+        assert_eq!(pluralizer.classify_word("GEESE"), WordClass::Irregular);
+        assert_eq!(pluralizer.classify_word("flox"), WordClass::Invariant);
+        assert_eq!(pluralizer.classify_word("house"), WordClass::Regular);
+        assert_eq!(pluralizer.classify_word("piano"), WordClass::Regular);
+        assert_eq!(pluralizer.classify_word("roofs"), WordClass::Regular);
+        assert_eq!(pluralizer.classify_word("gulf"), WordClass::Regular);
+        assert_eq!(pluralizer.classify_word("formula"), WordClass::Regular);
+
+        pluralizer.set_classical_mode(true);
+        assert_eq!(pluralizer.classify_word("formula"), WordClass::Irregular);
+
+        pluralizer.add_uncountable_rule(UncountableRule::String("flox".to_string()));
+        assert_eq!(pluralizer.classify_word("flox"), WordClass::Uncountable);
+    }
+
+    #[test]
+    fn keeps_blank_words_unchanged() {
+        use std::borrow::Cow;
+
+        for word in ["", " ", "   ", "\t\n"] {
+            assert_eq!(pluralize(word, 2, false), word);
+            assert_eq!(pluralize(word, 1, false), word);
+            assert_eq!(plural(word), word);
+            assert_eq!(singular(word), word);
+            assert_eq!(pluralize(word, 2, true), "2");
+            assert_eq!(pluralize(word, 1, true), "1");
+            assert_eq!(pluralize_with_separator(word, 3, "-"), "3");
+            assert_eq!(pluralize_count_after(word, 3), "3");
+            assert!(matches!(pluralize_cow(word, 2), Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn can_reference_multi_digit_capture_groups() {
+        let mut pluralizer = Pluralizer::empty();
+        let rule = Regex::new("(?i)^(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)$").unwrap();
+
+        pluralizer
+            .add_plural_rule(rule.clone(), "$12$11${10}$1-$1${1}0-$$".to_string())
+            .unwrap();
+        assert_eq!(pluralizer.plural("abcdefghijkl"), "lkja-aa0-$$");
+        assert_eq!(pluralizer.plural("ABCDEFGHIJKL"), "LKJA-AA0-$$");
+
+        assert_eq!(
+            pluralizer.add_singular_rule(rule, "$13".to_string()),
+            Err(RuleError::MissingCaptureGroup {
+                group: 13,
+                groups: 12
+            })
+        );
+        assert_eq!(
+            pluralizer.try_add_singular_rule("(a)", "${2}".to_string()),
+            Err(RuleError::MissingCaptureGroup {
+                group: 2,
+                groups: 1
+            })
+        );
+
+        // Unmatched groups and references of incomplete braces are left out or kept as is.
+        pluralizer
+            .try_add_singular_rule("^(x)?(y)$", "<$1|$2|${x}|${2>".to_string())
+            .unwrap();
+        assert_eq!(pluralizer.singular("y"), "<|y|${x}|${2>");
+    }
+
+    #[test]
+    fn can_pluralize_units() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.pluralize_unit(5.0, "kg"), "5 kg");
+        assert_eq!(pluralizer.pluralize_unit(1.0, "kg"), "1 kg");
+        assert_eq!(pluralizer.pluralize_unit(0.5, "L"), "0.5 L");
+        assert_eq!(pluralizer.pluralize_unit(100.0, "°C"), "100 °C");
+        assert_eq!(pluralizer.pluralize_unit(5.0, "kilogram"), "5 kilograms");
+        assert_eq!(pluralizer.pluralize_unit(1.0, "kilograms"), "1 kilogram");
+        assert_eq!(pluralizer.pluralize_unit(2.5, "inch"), "2.5 inches");
+        assert_eq!(pluralizer.pluralize_unit(3.0, "KG"), "3 KGs");
+
+        assert_eq!(pluralizer.pluralize_unit(300.0, "dpi"), "300 dpis");
+        pluralizer.add_unit_symbol("dpi".to_string());
+        assert_eq!(pluralizer.pluralize_unit(300.0, "dpi"), "300 dpi");
+
+        assert_eq!(Pluralizer::empty().pluralize_unit(5.0, "kg"), "5 kg");
+    }
+
+    #[test]
+    fn can_restrict_conversions_to_irregular_words() {
+        let mut pluralizer = Pluralizer::new();
+        pluralizer.set_strict_mode(true);
+        pluralizer.add_irregular_rule("snorp".to_string(), "snorpii".to_string());
+
+        assert_eq!(pluralizer.plural("snorp"), "snorpii");
+        assert_eq!(pluralizer.singular("Snorpii"), "Snorp");
+        assert_eq!(pluralizer.plural("goose"), "geese");
+        assert_eq!(pluralizer.plural("house"), "house");
+        assert_eq!(pluralizer.singular("Kubernetes"), "Kubernetes");
+        assert_eq!(pluralizer.singular("CPUs"), "CPUs");
+        assert_eq!(pluralizer.pluralize("box", 2, true), "2 box");
+        assert_eq!(
+            pluralizer.explain_plural("house").source,
+            TransformSource::Unchanged
+        );
+
+        pluralizer.set_strict_mode(false);
+        assert_eq!(pluralizer.plural("house"), "houses");
+    }
+
+    #[test]
+    fn can_verify_roundtrips() {
+        let mut pluralizer = Pluralizer::new();
+        let words = BASIC_TESTS
+            .iter()
+            .chain(SINGULAR_TESTS)
+            .map(|[singular, _]| *singular)
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            pluralizer.verify_roundtrip(&["House", "goose", "child's"]),
+            vec![]
+        );
+
+        pluralizer
+            .try_add_plural_rule("(?i)orp$", "orpen".to_string())
+            .unwrap();
+        assert_eq!(
+            pluralizer.verify_roundtrip(&["house", "blorp"]),
+            vec![RoundtripFailure {
+                word: "blorp".to_string(),
+                plural: "blorpen".to_string(),
+                singular: "blorpen".to_string(),
+            }]
+        );
+
+        pluralizer
+            .try_add_singular_rule("(?i)orpen$", "orp".to_string())
+            .unwrap();
+        assert_eq!(pluralizer.verify_roundtrip(&["blorp"]), vec![]);
+        assert_eq!(pluralizer.verify_roundtrip(&words), vec![]);
+    }
+
+    #[test]
+    fn can_pluralize_any_integer_count() {
+        assert_eq!(pluralize("house", 2u8, true), "2 houses");
+        assert_eq!(pluralize("houses", 1u32, true), "1 house");
+        assert_eq!(pluralize("house", 3usize, true), "3 houses");
+        assert_eq!(pluralize("houses", -1i64, true), "-1 house");
+        assert_eq!(pluralize("house", 0i16, false), "houses");
+        assert_eq!(
+            pluralize("house", u128::MAX, true),
+            "340282366920938463463374607431768211455 houses"
+        );
+        assert_eq!(
+            pluralize("house", i128::MIN, true),
+            "-170141183460469231731687303715884105728 houses"
+        );
+
+        assert_eq!(u64::MAX.saturating_isize(), isize::MAX);
+        assert_eq!(i128::MIN.saturating_isize(), isize::MIN);
+        assert_eq!((-1i8).saturating_isize(), -1);
+    }
+
+    #[test]
+    fn can_pluralize_gendered_nouns() {
+        let casa = GenderedNoun::new("casa", Gender::Feminine);
+        let luces = GenderedNoun::new("luces", Gender::Feminine);
+        let papel = GenderedNoun::new("papel", Gender::Masculine);
+        let kind = GenderedNoun::new("Kind", Gender::Neuter);
+        let frau = GenderedNoun::new("Frau", Gender::Feminine);
+        let house = GenderedNoun::new("house", Gender::Neuter);
+
+        assert_eq!(pluralize_gendered(&casa, 1, "es").unwrap(), "la casa");
+        assert_eq!(pluralize_gendered(&casa, 0, "es-MX").unwrap(), "las casas");
+        assert_eq!(pluralize_gendered(&luces, 1, "es").unwrap(), "la luz");
+        assert_eq!(pluralize_gendered(&papel, 1, "ES").unwrap(), "el papel");
+        assert_eq!(pluralize_gendered(&papel, 3, "es").unwrap(), "los papeles");
+        assert_eq!(pluralize_gendered(&kind, 1, "de").unwrap(), "das Kind");
+        assert_eq!(pluralize_gendered(&kind, 2, "de_AT").unwrap(), "die Kinder");
+        assert_eq!(pluralize_gendered(&frau, -1, "de").unwrap(), "die Frau");
+        assert_eq!(pluralize_gendered(&house, 2, "en").unwrap(), "the houses");
+        assert_eq!(pluralize_gendered(&house, 2, "fr"), None);
+    }
+
+    #[test]
+    fn can_test_matching_rules() {
+        let mut pluralizer = Pluralizer::new();
+        let owned = |rules: &[(&str, &str)]| {
+            rules
+                .iter()
+                .map(|(pattern, result)| (pattern.to_string(), result.to_string()))
+                .collect::<Vec<(String, String)>>()
+        };
+
+        assert_eq!(
+            pluralizer.test_plural_rules("Fox"),
+            owned(&[("(?i)(x|ch|ss|sh|z)$", "Foxes"), ("(?i)s?$", "Foxs")])
+        );
+        assert_eq!(
+            pluralizer.test_plural_rules("")[..],
+            owned(&[("(?i)s?$", "s")])[..]
+        );
+
+        pluralizer
+            .try_add_plural_rule("(?i)(o)x$", "$1xen".to_string())
+            .unwrap();
+        pluralizer
+            .add_plural_rule_with_priority(
+                Regex::new("(?i)x$").unwrap(),
+                "xii".to_string(),
+                Priority::First,
+            )
+            .unwrap();
+
+        let rules = pluralizer.test_plural_rules("flox");
+        let patterns = rules.iter().map(|(p, _)| p.as_str()).collect::<Vec<&str>>();
+
+        assert_eq!(
+            patterns,
+            ["(?i)(o)x$", "(?i)(x|ch|ss|sh|z)$", "(?i)s?$", "(?i)x$"]
+        );
+        assert_eq!(rules[0].1, pluralizer.plural("flox"));
+        assert_eq!(rules[3].1, "floxii");
+
+        let singular = pluralizer.test_singular_rules("boxes");
+        assert_eq!(singular[0].1, pluralizer.singular("boxes"));
+        assert!(singular.contains(&("(?i)s$".to_string(), "boxe".to_string())));
+    }
+
+    #[test]
+    fn can_pluralize_words_ending_in_o() {
+        let mut pluralizer = Pluralizer::new();
+
+        for [singular, plural] in [
+            ["potato", "potatoes"],
+            ["tomato", "tomatoes"],
+            ["hero", "heroes"],
+            ["echo", "echoes"],
+            ["photo", "photos"],
+            ["piano", "pianos"],
+            ["kilo", "kilos"],
+            ["gelato", "gelatos"],
+            ["Staccato", "Staccatos"],
+            ["VIDEO", "VIDEOS"],
+        ] {
+            assert_eq!(pluralizer.plural(singular), plural);
+            assert_eq!(pluralizer.singular(plural), singular);
+        }
+
+        assert_eq!(pluralizer.plural("tornato"), "tornatoes");
+        pluralizer.add_o_exception("Tornato");
+        assert_eq!(pluralizer.plural("tornato"), "tornatos");
+        assert_eq!(pluralizer.singular("tornatos"), "tornato");
+        assert_eq!(pluralizer.classify_word("photo"), WordClass::Regular);
+        assert_eq!(
+            pluralizer.explain_plural("photo").source,
+            TransformSource::Irregular
+        );
+    }
+
+    #[test]
+    fn can_read_the_default_tables() {
+        let config = Pluralizer::new().export_config();
+        let pairs = |rules: &[(&str, &str)]| {
+            rules
+                .iter()
+                .map(|(pattern, placement)| RegexRule {
+                    pattern: pattern.to_string(),
+                    placement: placement.to_string(),
+                })
+                .collect::<Vec<RegexRule>>()
+        };
+
+        assert!(default_irregular_rules().contains(&("goose", "geese")));
+        assert_eq!(config.plural, pairs(default_plural_rules()));
+        assert_eq!(config.singular, pairs(default_singular_rules()));
+        assert_eq!(config.uncountable, default_uncountable_rules());
+        assert_eq!(config.uncountable_regex, default_uncountable_regex_rules());
+
+        for (singular, plural) in default_irregular_rules() {
+            assert!(config
+                .irregular
+                .contains(&(singular.to_string(), plural.to_string())));
+        }
+    }
+
+    #[test]
+    fn can_humanize_identifiers() {
+        assert_eq!(humanize("first_name"), "First name");
+        assert_eq!(humanize("user_id"), "User");
+        assert_eq!(humanize("parent_user_id"), "Parent user");
+        assert_eq!(humanize("id"), "Id");
+        assert_eq!(humanize("_id"), "Id");
+        assert_eq!(humanize("identity"), "Identity");
+        assert_eq!(
+            humanize("shipping__address___line"),
+            "Shipping address line"
+        );
+        assert_eq!(humanize("_private_"), "Private");
+        assert_eq!(humanize("already spaced  words"), "Already spaced words");
+        assert_eq!(humanize("HTTP_status"), "Http status");
+        assert_eq!(humanize(""), "");
+    }
+
+    #[test]
+    fn can_singularize_classical_plurals() {
+        let pluralizer = Pluralizer::new();
+        let pairs = [
+            ["datum", "data"],
+            ["criterion", "criteria"],
+            ["phenomenon", "phenomena"],
+            ["bacterium", "bacteria"],
+            ["stratum", "strata"],
+            ["erratum", "errata"],
+            ["automaton", "automata"],
+            ["polyhedron", "polyhedra"],
+            ["quantum", "quanta"],
+            ["spectrum", "spectra"],
+            ["corrigendum", "corrigenda"],
+            ["ganglion", "ganglia"],
+        ];
+
+        for [singular, plural] in pairs {
+            assert_eq!(pluralizer.plural(singular), plural);
+            assert_eq!(pluralizer.singular(plural), singular);
+            assert_eq!(pluralizer.plural(plural), plural);
+            assert_eq!(pluralizer.singular(singular), singular);
+        }
+
+        let singulars = pairs.map(|[singular, _]| singular);
+        assert_eq!(pluralizer.verify_roundtrip(&singulars), vec![]);
+
+        // Words usually pluralized with `s` still singularize their classical plural.
+        for [singular, plural] in [
+            ["memorandum", "memoranda"],
+            ["referendum", "referenda"],
+            ["stadium", "stadia"],
+            ["maximum", "maxima"],
+        ] {
+            assert_eq!(pluralizer.singular(plural), singular);
+            assert_eq!(pluralizer.plural(singular), format!("{}s", singular));
+        }
+    }
+
+    #[test]
+    fn can_keep_the_original_casing() {
+        for [word, plural] in [
+            ["iPhone", "iPhones"],
+            ["eBay", "eBays"],
+            ["iPHONE", "iPHONEs"],
+            ["McMatrix", "McMatrices"],
+            ["GeeSE", "GeeSE"],
+            ["GooSE", "Geese"],
+            ["ChilD", "ChilDren"],
+            ["PERson", "PEople"],
+        ] {
+            assert_eq!(pluralize_cased(word, 2, CaseMode::Keep), plural);
+        }
+
+        assert_eq!(pluralize_cased("GeeSE", 1, CaseMode::Keep), "Goose");
+        assert_eq!(pluralize_cased("GeeSE", 2, CaseMode::Preserve), "Geese");
+        assert_eq!(pluralize_cased("iPhones", 1, CaseMode::Keep), "iPhone");
+        assert_eq!(pluralize_cased("", 2, CaseMode::Keep), "");
+    }
+
+    #[cfg(all(feature = "std", not(feature = "wasm")))]
+    #[test]
+    fn recovers_from_a_poisoned_lock() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Pluralizer>();
+        assert_send_sync::<RuleSnapshot>();
+
+        let panicked = std::thread::spawn(|| {
+            let _guard = PLURALIZER.lock();
+            panic!("poison the rules");
+        })
+        .join();
+
+        assert!(panicked.is_err());
+        assert!(PLURALIZER.is_poisoned());
+
+        assert_eq!(pluralize("house", 2, true), "2 houses");
+        assert_eq!(singular("geese"), "goose");
+
+        let _shared = lock_shared_rules();
+        add_irregular_rule("zlorp".to_string(), "zlorpii".to_string());
+        assert_eq!(plural("zlorp"), "zlorpii");
+    }
+
+    #[test]
+    fn can_inflect_words_once() {
+        let mut pluralizer = Pluralizer::new();
+        let geese = pluralizer.inflect("Geese");
+
+        assert_eq!(geese.singular, "Goose");
+        assert_eq!(geese.plural, "Geese");
+        assert_eq!(geese.for_count(1), "Goose");
+        assert_eq!(geese.for_count(-1), "Goose");
+        assert_eq!(geese.for_count(0), "Geese");
+        assert_eq!(geese.for_count(2), "Geese");
+
+        for count in -3..=3 {
+            assert_eq!(
+                geese.for_count(count),
+                pluralizer.pluralize("Geese", count, false)
+            );
+        }
+
+        pluralizer.set_singular_predicate(|count| count.abs() <= 1);
+        let house = pluralizer.inflect("house");
+
+        assert_eq!(house.for_count(0), "house");
+        assert_eq!(geese.for_count(0), "Geese");
+    }
+
+    #[test]
+    fn can_repluralize_words_with_a_count() {
+        let pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.repluralize("1 house", 3), "3 houses");
+        assert_eq!(pluralizer.repluralize("3 apples", 1), "1 apple");
+        assert_eq!(pluralizer.repluralize("  -2\tdegrees", -1), "-1 degree");
+        assert_eq!(pluralizer.repluralize("+4  mice", 1), "1 mouse");
+        assert_eq!(pluralizer.repluralize("house", 2), "2 houses");
+        assert_eq!(pluralizer.repluralize("3rd house", 2), "2 3rd houses");
+        assert_eq!(pluralizer.repluralize("- house", 2), "2 - houses");
+        assert_eq!(pluralizer.repluralize("3", 2), "2");
+        assert_eq!(pluralizer.repluralize("", 2), "2");
+    }
+
+    #[test]
+    fn can_use_portuguese_rules() {
+        let portuguese = locales::pt::pluralizer();
+        let cases = [
+            ["casa", "casas"],
+            ["coração", "corações"],
+            ["leão", "leões"],
+            ["pão", "pães"],
+            ["alemão", "alemães"],
+            ["mão", "mãos"],
+            ["irmão", "irmãos"],
+            ["animal", "animais"],
+            ["papel", "papéis"],
+            ["farol", "faróis"],
+            ["azul", "azuis"],
+            ["nível", "níveis"],
+            ["fuzil", "fuzis"],
+            ["funil", "funis"],
+            ["barril", "barris"],
+            ["perfil", "perfis"],
+            ["fácil", "fáceis"],
+            ["fóssil", "fósseis"],
+            ["réptil", "répteis"],
+            ["útil", "úteis"],
+            ["rubi", "rubis"],
+            ["homem", "homens"],
+            ["jardim", "jardins"],
+            ["flor", "flores"],
+            ["luz", "luzes"],
+            ["pai", "pais"],
+            ["lápis", "lápis"],
+            ["Coração", "Corações"],
+            ["CORAÇÃO", "CORAÇÕES"],
+            ["Homem", "Homens"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(portuguese.plural(singular_word), plural_word);
+            assert_eq!(portuguese.singular(plural_word), singular_word);
+        }
+
+        assert_eq!(portuguese.pluralize("mão", 2, true), "2 mãos");
+        assert_eq!(
+            portuguese.pluralize_phrase("casa de campo", 2),
+            "casas de campo"
+        );
+    }
+
+    #[test]
+    fn can_limit_the_word_length() {
+        let mut pluralizer = Pluralizer::new();
+        let long = "house".repeat(10);
+
+        assert_eq!(pluralizer.plural(&long), format!("{}s", long));
+
+        pluralizer.set_max_word_len(Some(long.len() - 1));
+
+        assert_eq!(pluralizer.plural(&long), long);
+        assert_eq!(pluralizer.pluralize(&long, 2, true), format!("2 {}", long));
+        assert_eq!(pluralizer.plural("house"), "houses");
+        assert_eq!(pluralizer.singular("houses"), "house");
+
+        pluralizer.set_max_word_len(None);
+
+        assert_eq!(pluralizer.plural(&long), format!("{}s", long));
+    }
+
+    #[test]
+    fn can_split_suffix_deltas() {
+        let pluralizer = Pluralizer::new();
+        let cases = [
+            ["box", "", "es"],
+            ["baby", "y", "ies"],
+            ["babies", "y", "ies"],
+            ["house", "", "s"],
+            ["ox", "", "en"],
+            ["person", "person", "people"],
+            ["people", "person", "people"],
+            ["goose", "goose", "geese"],
+            ["child", "", "ren"],
+            ["echo", "", "es"],
+            ["me", "me", "us"],
+            ["is", "is", "are"],
+            ["sheep", "", ""],
+            ["cities", "y", "ies"],
+        ];
+
+        for [word, singular_suffix, plural_suffix] in cases {
+            assert_eq!(
+                pluralizer.suffix_delta(word),
+                (singular_suffix.to_string(), plural_suffix.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn can_build_pluralizers() {
+        let pluralizer = Pluralizer::builder()
+            .with_defaults()
+            .irregular("cow", "kine")
+            .uncountable("blorp")
+            .plural_rule(Regex::new("(?i)(zl)orp$").unwrap(), "$1orpen")
+            .singular_rule(Regex::new("(?i)(zl)orpen$").unwrap(), "$1orp")
+            .build()
+            .unwrap();
+
+        assert_eq!(pluralizer.plural("cow"), "kine");
+        assert_eq!(pluralizer.singular("kine"), "cow");
+        assert_eq!(pluralizer.plural("blorp"), "blorp");
+        assert_eq!(pluralizer.plural("zlorp"), "zlorpen");
+        assert_eq!(pluralizer.singular("zlorpen"), "zlorp");
+        assert_eq!(pluralizer.plural("house"), "houses");
+
+        let empty = Pluralizer::builder()
+            .irregular("cow", "kine")
+            .build()
+            .unwrap();
+
+        assert_eq!(empty.plural("cow"), "kine");
+        assert_eq!(empty.plural("house"), "house");
+
+        let result = Pluralizer::builder()
+            .plural_rule(Regex::new("(?i)zlorp$").unwrap(), "$1en")
+            .build();
+
+        assert_eq!(
+            result.unwrap_err(),
+            RuleError::MissingCaptureGroup {
+                group: 1,
+                groups: 0
+            }
+        );
+    }
+
+    #[test]
+    fn only_changes_some_f_endings_to_ves() {
+        let pluralizer = Pluralizer::new();
+        let cases = [
+            ["leaf", "leaves"],
+            ["knife", "knives"],
+            ["wolf", "wolves"],
+            ["half", "halves"],
+            ["loaf", "loaves"],
+            ["roof", "roofs"],
+            ["belief", "beliefs"],
+            ["chief", "chiefs"],
+            ["gulf", "gulfs"],
+            ["oaf", "oafs"],
+            ["safe", "safes"],
+            ["Gulf", "Gulfs"],
+            ["ROOF", "ROOFS"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(pluralizer.plural(singular_word), plural_word);
+            assert_eq!(pluralizer.singular(plural_word), singular_word);
+        }
+    }
+
+    #[test]
+    fn can_pluralize_without_a_bool() {
+        let pluralizer = Pluralizer::new();
+
+        for count in -3..=3 {
+            assert_eq!(
+                pluralizer.pluralize_with_count("House", count),
+                pluralizer.pluralize("House", count, true)
+            );
+            assert_eq!(
+                pluralizer.pluralize_word("House", count),
+                pluralizer.pluralize("House", count, false)
+            );
+        }
+
+        assert_eq!(pluralizer.pluralize_with_count("House", 2u32), "2 Houses");
+        assert_eq!(pluralizer.pluralize_word("Houses", 1usize), "House");
+        assert_eq!(pluralize_with_count("Houses", 1), "1 House");
+        assert_eq!(pluralize_word("House", 2), "Houses");
+    }
+
+    #[test]
+    fn can_pluralize_words_ending_in_sis() {
+        let pluralizer = Pluralizer::new();
+        let cases = [
+            ["analysis", "analyses"],
+            ["crisis", "crises"],
+            ["thesis", "theses"],
+            ["hypothesis", "hypotheses"],
+            ["synthesis", "syntheses"],
+            ["diagnosis", "diagnoses"],
+            ["prognosis", "prognoses"],
+            ["oasis", "oases"],
+            ["metastasis", "metastases"],
+            ["neurosis", "neuroses"],
+            ["psychosis", "psychoses"],
+            ["thrombosis", "thromboses"],
+            ["Oasis", "Oases"],
+            ["CRISIS", "CRISES"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(pluralizer.plural(singular_word), plural_word);
+            assert_eq!(pluralizer.singular(plural_word), singular_word);
+        }
+
+        // Also the plural of "base", which is the more common singular.
+        assert_eq!(pluralizer.plural("basis"), "bases");
+        assert_eq!(pluralizer.singular("bases"), "base");
+        assert_eq!(pluralizer.singular("roses"), "rose");
+        assert_eq!(pluralizer.singular("purposes"), "purpose");
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn can_report_metrics() {
+        use std::sync::{Arc, Mutex};
+
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let mut pluralizer = Pluralizer::new();
+        let reported = paths.clone();
+        pluralizer.set_metrics_hook(move |path| reported.lock().unwrap().push(path));
+
+        pluralizer.plural("goose");
+        pluralizer.plural("geese");
+        pluralizer.plural("house");
+        pluralizer.plural("rice");
+        pluralizer.singular("zorp");
+        pluralizer.plural("mother-in-law");
+        pluralizer.plural("");
+
+        assert_eq!(
+            *paths.lock().unwrap(),
+            [
+                Path::Irregular,
+                Path::Kept,
+                Path::Rule,
+                Path::Uncountable,
+                Path::Unchanged,
+                Path::Rule,
+            ]
+        );
+    }
+
+    #[test]
+    fn can_isolate_counts_for_bidi_text() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.pluralize("house", 2, true), "2 houses");
+
+        pluralizer.set_bidi_isolation(true);
+
+        assert_eq!(
+            pluralizer.pluralize("house", 2, true),
+            "\u{2066}2\u{2069} houses"
+        );
+        assert_eq!(
+            pluralizer.pluralize_with_separator("house", 1, "\u{a0}"),
+            "\u{2066}1\u{2069}\u{a0}house"
+        );
+        assert_eq!(
+            pluralizer.pluralize_count_after("house", 3),
+            "houses \u{2066}3\u{2069}"
+        );
+        assert_eq!(
+            pluralizer.pluralize_range("house", 2, 4),
+            "\u{2066}2\u{2013}4\u{2069} houses"
+        );
+        assert_eq!(
+            pluralizer.pluralize_unit(2.5, "km"),
+            "\u{2066}2.5\u{2069} km"
+        );
+        assert_eq!(pluralizer.pluralize("", 2, true), "\u{2066}2\u{2069}");
+        assert_eq!(pluralizer.pluralize("house", 2, false), "houses");
+    }
+
+    #[test]
+    fn can_pluralize_with_macros() {
+        assert_eq!(crate::pluralize!("House", 2), "Houses");
+        assert_eq!(crate::pluralize!("Houses", 1), "House");
+        assert_eq!(crate::pluralize!("House", 3usize,), "Houses");
+        assert_eq!(crate::count!(2, "House"), "2 Houses");
+        assert_eq!(crate::count!(1, "Houses"), "1 House");
+        assert_eq!(crate::count!(-1, "degrees"), "-1 degree");
+    }
+
+    #[test]
+    fn only_changes_y_to_ies_after_consonants() {
+        let pluralizer = Pluralizer::new();
+        let cases = [
+            ["baby", "babies"],
+            ["city", "cities"],
+            ["spy", "spies"],
+            ["soliloquy", "soliloquies"],
+            ["day", "days"],
+            ["key", "keys"],
+            ["boy", "boys"],
+            ["guy", "guys"],
+            ["buy", "buys"],
+            ["journey", "journeys"],
+            ["Baby", "Babies"],
+            ["Day", "Days"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(pluralizer.plural(singular_word), plural_word);
+            assert_eq!(pluralizer.singular(plural_word), singular_word);
+        }
+    }
+
+    #[test]
+    fn can_format_counted_words() {
+        assert_eq!(
+            format!("You have {}", Counted::new("message", 3, true)),
+            "You have 3 messages"
+        );
+        assert_eq!(Counted::new("messages", 1, true).to_string(), "1 message");
+        assert_eq!(Counted::new("message", 0, false).to_string(), "messages");
+        assert_eq!(
+            format!("[{:>8}]", Counted::new("messages", 1, false)),
+            "[ message]"
+        );
+    }
+
+    #[test]
+    fn keeps_contractions_unchanged() {
+        let mut pluralizer = Pluralizer::new();
+
+        for word in [
+            "it's",
+            "It's",
+            "IT'S",
+            "it\u{2019}s",
+            "he's",
+            "don't",
+            "let's",
+            "(it's)",
+        ] {
+            assert_eq!(pluralizer.plural(word), word);
+            assert_eq!(pluralizer.singular(word), word);
+        }
+
+        assert_eq!(pluralizer.plural("cat's"), "cats'");
+        assert_eq!(pluralizer.singular("cats'"), "cat's");
+        assert_eq!(pluralizer.classify_word("it's"), WordClass::Invariant);
+
+        assert_eq!(pluralizer.plural("y'all"), "y'alls");
+
+        pluralizer.add_contraction("Y'all");
+
+        assert_eq!(pluralizer.plural("y'all"), "y'all");
+        assert_eq!(pluralizer.pluralize("it's", 2, false), "it's");
+    }
+
+    #[test]
+    fn can_name_collectives() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.collective("fish"), Some("school".to_string()));
+        assert_eq!(pluralizer.collective("Lions"), Some("pride".to_string()));
+        assert_eq!(pluralizer.collective("geese"), Some("gaggle".to_string()));
+        assert_eq!(pluralizer.collective("house"), None);
+
+        assert_eq!(
+            pluralizer.pluralize_collective("lion", 1),
+            Some("a pride of lions".to_string())
+        );
+        assert_eq!(
+            pluralizer.pluralize_collective("fish", 3),
+            Some("3 schools of fish".to_string())
+        );
+        assert_eq!(
+            pluralizer.pluralize_collective("owl", 1),
+            Some("a parliament of owls".to_string())
+        );
+        assert_eq!(
+            pluralizer.pluralize_collective("ant", 1),
+            Some("a colony of ants".to_string())
+        );
+        assert_eq!(pluralizer.pluralize_collective("house", 2), None);
+
+        pluralizer.add_collective("kittens", "litter");
+
+        assert_eq!(
+            pluralizer.pluralize_collective("kitten", 2),
+            Some("2 litters of kittens".to_string())
+        );
+        assert_eq!(Pluralizer::empty().collective("fish"), None);
+    }
+
+    #[test]
+    fn rejects_unanchored_rules_when_checked() {
+        let mut pluralizer = Pluralizer::new();
+
+        for pattern in [
+            "(?i)zlorp$",
+            "(?i)(zl)(orp)$",
+            r"(?i)zlorp\z",
+            r"(?i)zlorp\\$",
+        ] {
+            assert_eq!(
+                pluralizer.add_plural_rule_checked(Regex::new(pattern).unwrap(), "x".to_string()),
+                Ok(()),
+                "{}",
+                pattern
+            );
+        }
+
+        for pattern in ["(?i)zlorp", r"(?i)zlorp\$", "(?i)(zlorp$)s", "$zlorp"] {
+            assert_eq!(
+                pluralizer.add_singular_rule_checked(Regex::new(pattern).unwrap(), "x".to_string()),
+                Err(RuleError::Unanchored),
+                "{}",
+                pattern
+            );
+        }
+
+        assert_eq!(
+            pluralizer.add_plural_rule_checked(Regex::new("(?i)zorp$").unwrap(), "$1".to_string()),
+            Err(RuleError::MissingCaptureGroup {
+                group: 1,
+                groups: 0
+            })
+        );
+        assert_eq!(
+            pluralizer.add_plural_rule(Regex::new("(?i)zorp").unwrap(), "zorpen".to_string()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn can_agree_with_counts() {
+        let mut pluralizer = Pluralizer::new();
+
+        assert_eq!(pluralizer.verb_for_count("is", "are", 1), "is");
+        assert_eq!(pluralizer.verb_for_count("is", "are", -1), "is");
+        assert_eq!(pluralizer.verb_for_count("is", "are", 0), "are");
+        assert_eq!(pluralizer.verb_for_count("goes", "go", 2), "go");
+
+        for [singular_word, plural_word] in [
+            ["is", "are"],
+            ["was", "were"],
+            ["has", "have"],
+            ["this", "these"],
+        ] {
+            assert_eq!(pluralizer.pluralize_word(singular_word, 2), plural_word);
+            assert_eq!(pluralizer.pluralize_word(plural_word, 1), singular_word);
+        }
+
+        assert_eq!(pluralizer.agree("house", 1), "this house");
+        assert_eq!(pluralizer.agree("house", 2), "these houses");
+        assert_eq!(
+            pluralizer.agree("boxes of chocolates", 1),
+            "this box of chocolates"
+        );
+
+        pluralizer.set_singular_predicate(|count| count.abs() <= 1);
+
+        assert_eq!(pluralizer.verb_for_count("is", "are", 0), "is");
+        assert_eq!(pluralizer.agree("houses", 0), "this house");
+    }
+
+    #[test]
+    fn can_clear_user_rules() {
+        let mut pluralizer = Pluralizer::new();
+        let rules = pluralizer.rule_count();
+
+        pluralizer.add_irregular_rule("cow".to_string(), "kine".to_string());
+        pluralizer.remove_irregular_rule("goose");
+        pluralizer.add_uncountable_rule(UncountableRule::String("blorp".to_string()));
+        pluralizer.try_add_uncountable_rule("(?i)snorp$").unwrap();
+        pluralizer
+            .add_plural_rule(Regex::new("(?i)(zl)orp$").unwrap(), "$1orpen".to_string())
+            .unwrap();
+        pluralizer
+            .add_plural_rule_with_priority(
+                Regex::new("(?i)us$").unwrap(),
+                "uses".to_string(),
+                Priority::First,
+            )
+            .unwrap();
+        pluralizer.add_o_exception("tornato");
+        pluralizer.add_contraction("y'all");
+        pluralizer.set_classical_mode(true);
+
+        assert_eq!(pluralizer.plural("cow"), "kine");
+        assert_eq!(pluralizer.plural("campus"), "campuses");
+
+        pluralizer.clear_user_rules();
+
+        assert_eq!(pluralizer.rule_count(), rules);
+        assert_eq!(pluralizer.plural("cow"), "cows");
+        assert_eq!(pluralizer.plural("goose"), "geese");
+        assert_eq!(pluralizer.plural("blorp"), "blorps");
+        assert_eq!(pluralizer.plural("snorp"), "snorps");
+        assert_eq!(pluralizer.plural("zlorp"), "zlorps");
+        assert_eq!(pluralizer.plural("cactus"), "cacti");
+        assert_eq!(pluralizer.plural("tornato"), "tornatoes");
+        assert_eq!(pluralizer.plural("y'all"), "y'alls");
+        assert_eq!(pluralizer.plural("photo"), "photos");
+        assert_eq!(pluralizer.plural("rice"), "rice");
+        assert_eq!(pluralizer.plural("formula"), "formulae");
+
+        let mut empty = Pluralizer::empty();
+        empty.add_irregular_rule("cow".to_string(), "kine".to_string());
+        empty.clear_user_rules();
+
+        assert_eq!(empty.plural("cow"), "cow");
+    }
+
+    #[test]
+    fn can_pluralize_with_owned_rules() {
+        let mut rules = Pluralizer::empty();
+        rules.add_irregular_rule("zlorp".to_string(), "zlorpen".to_string());
+
+        assert_eq!(pluralize_with_rules("zlorp", 2, &rules), "zlorpen");
+        assert_eq!(pluralize_with_rules("zlorpen", 1, &rules), "zlorp");
+        assert_eq!(pluralize_with_rules("zlorp", 3_u64, &rules), "zlorpen");
+        assert_eq!(pluralize_with_rules("zlorpen", 1_usize, &rules), "zlorp");
+        assert_eq!(pluralize_with_rules("house", 2, &rules), "house");
+        assert_eq!(
+            pluralize_with_rules("house", 2, &Pluralizer::new()),
+            "houses"
+        );
+    }
+
+    #[test]
+    fn adds_es_after_sibilants() {
+        let pluralizer = Pluralizer::new();
+        let cases = [
+            ["bus", "buses"],
+            ["gas", "gases"],
+            ["kiss", "kisses"],
+            ["glass", "glasses"],
+            ["dish", "dishes"],
+            ["wish", "wishes"],
+            ["church", "churches"],
+            ["match", "matches"],
+            ["box", "boxes"],
+            ["tax", "taxes"],
+            ["buzz", "buzzes"],
+            ["waltz", "waltzes"],
+            ["blitz", "blitzes"],
+            ["quiz", "quizzes"],
+            ["fez", "fezzes"],
+            ["topaz", "topazes"],
+            ["Topaz", "Topazes"],
+            ["Church", "Churches"],
+            ["house", "houses"],
+            ["horse", "horses"],
+            ["size", "sizes"],
+            ["maze", "mazes"],
+            ["breeze", "breezes"],
+            ["bronze", "bronzes"],
+            ["doze", "dozes"],
+            ["analyze", "analyzes"],
+        ];
+
+        for [singular_word, plural_word] in cases {
+            assert_eq!(pluralizer.plural(singular_word), plural_word);
+            assert_eq!(pluralizer.singular(plural_word), singular_word);
+        }
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn can_use_the_wasm_bindings() {
+        let mut pluralizer = crate::wasm::WasmPluralizer::new();
+
+        assert_eq!(pluralizer.pluralize("house", 2, true), "2 houses");
+        assert_eq!(pluralizer.plural("goose"), "geese");
+        assert_eq!(pluralizer.singular("geese"), "goose");
+
+        pluralizer.add_irregular_rule("zlorp".to_string(), "zlorpen".to_string());
+
+        assert_eq!(pluralizer.plural("zlorp"), "zlorpen");
+        assert_eq!(plural("zlorp"), "zlorps");
+    }
+
+    #[test]
+    fn can_pluralize_latin_neuters() {
+        let mut pluralizer = Pluralizer::new();
+        let always = [
+            ["bacterium", "bacteria"],
+            ["curriculum", "curricula"],
+            ["datum", "data"],
+            ["millennium", "millennia"],
+            ["symposium", "symposia"],
+        ];
+        let ambiguous = [
+            ["forum", "forums", "fora"],
+            ["medium", "mediums", "media"],
+            ["consortium", "consortiums", "consortia"],
+            ["optimum", "optimums", "optima"],
+        ];
+
+        for classical in [false, true] {
+            pluralizer.set_classical_mode(classical);
+
+            for [singular, plural] in always {
+                assert_eq!(pluralizer.plural(singular), plural);
+                assert_eq!(pluralizer.singular(plural), singular);
+            }
+
+            for word in ["album", "museum", "premium", "vacuum"] {
+                assert_eq!(pluralizer.plural(word), format!("{}s", word));
+                assert_eq!(pluralizer.singular(&format!("{}s", word)), word);
+            }
+
+            for [singular, modern, latin] in ambiguous {
+                let plural = if classical { latin } else { modern };
+                assert_eq!(pluralizer.plural(singular), plural);
+                assert_eq!(pluralizer.singular(plural), singular);
+                assert_eq!(pluralizer.singular(modern), singular);
+            }
+        }
+
+        pluralizer.set_classical_mode(false);
+        assert_eq!(pluralizer.singular("fora"), "forum");
+        assert_eq!(pluralizer.singular("media"), "media");
+        assert_eq!(pluralizer.singular("anaphora"), "anaphora");
+    }
+
+    #[test]
+    fn can_split_pluralized_parts() {
+        let pluralizer = Pluralizer::new();
+        let cases = [
+            ("box", 2, "box", "es"),
+            ("box", 1, "box", ""),
+            ("baby", 2, "bab", "ies"),
+            ("babies", 1, "bab", "y"),
+            ("Person", 3, "People", ""),
+            ("person", 1, "person", ""),
+            ("child", 2, "child", "ren"),
+            ("children", 1, "child", ""),
+            ("goose", 2, "geese", ""),
+            ("ox", 2, "ox", "en"),
+            ("sheep", 2, "sheep", ""),
+            ("me", 2, "us", ""),
+            ("is", 1, "is", ""),
+        ];
+
+        for (word, count, stem, suffix) in cases {
+            let parts = pluralizer.pluralize_parts(word, count);
+            assert_eq!(parts, (stem.to_string(), suffix.to_string()));
+            assert_eq!(parts.0 + &parts.1, pluralizer.pluralize_word(word, count));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn cache_evicts_the_least_recently_used_word() {
+        use crate::cache::ResultCache;
+        use crate::pluralizer::Form;
+
+        let cache = ResultCache::new(2);
+        cache.insert("blorp", Form::Plural, "blorps");
+        cache.insert("snorps", Form::Singular, "snorp");
+
+        assert_eq!(cache.get("blorp", Form::Plural).as_deref(), Some("blorps"));
+
+        cache.insert("zlorp", Form::Plural, "zlorpen");
+        assert_eq!(cache.get("snorps", Form::Singular), None);
+        assert_eq!(cache.get("blorp", Form::Plural).as_deref(), Some("blorps"));
+        assert_eq!(cache.get("zlorp", Form::Plural).as_deref(), Some("zlorpen"));
+
+        cache.insert("zlorp", Form::Plural, "zlorps");
+        cache.insert("zorp", Form::Plural, "zorps");
+        assert_eq!(cache.get("blorp", Form::Plural), None);
+        assert_eq!(cache.get("zlorp", Form::Plural).as_deref(), Some("zlorps"));
+    }
+
+    #[test]
+    fn scopes_keep_rules_added_outside_them() {
+        let _shared = lock_shared_rules();
+        let base = snapshot();
+
+        add_irregular_rule("snorp".to_string(), "snorpen".to_string());
+
+        {
+            let scope = scoped();
+            scope.add_irregular_rule("blorp".to_string(), "blorpen".to_string());
+            scope.add_irregular_rule("snorp".to_string(), "snorpii".to_string());
+            scope.add_uncountable_rule(UncountableRule::String("glorp".to_string()));
+            scope.add_uncountable_rule(UncountableRule::Regex(Regex::new("(?i)flib$").unwrap()));
+
+            add_irregular_rule("zlorp".to_string(), "zlorpen".to_string());
+            add_uncountable_rule(UncountableRule::String("zorp".to_string()));
+
+            assert_eq!(plural("snorp"), "snorpii");
+            assert_eq!(plural("flib"), "flib");
+        }
+
+        assert_eq!(plural("blorp"), "blorps");
+        assert_eq!(plural("snorp"), "snorpen");
+        assert_eq!(plural("glorp"), "glorps");
+        assert_eq!(plural("flib"), "flibs");
+        assert_eq!(plural("zlorp"), "zlorpen");
+        assert_eq!(plural("zorp"), "zorp");
+
+        restore(base);
+    }
+
+    #[test]
+    fn can_prefix_articles_to_numbers() {
+        for (word, article) in [
+            ("8", "an"),
+            ("8th", "an"),
+            ("80", "an"),
+            ("86", "an"),
+            ("800", "an"),
+            ("11", "an"),
+            ("18", "an"),
+            ("11,000", "an"),
+            ("18000", "an"),
+            ("110", "a"),
+            ("181", "a"),
+            ("1", "a"),
+            ("7", "a"),
+            ("100", "a"),
+        ] {
+            assert_eq!(with_article(word), format!("{} {}", article, word));
+        }
+    }
+
+    #[test]
+    fn blank_words_get_no_article() {
+        assert_eq!(with_article(""), "");
+        assert_eq!(with_article("  "), "  ");
+    }
+
+    #[test]
+    fn gendered_nouns_follow_the_singular_predicate() {
+        let _shared = lock_shared_rules();
+        let base = snapshot();
+        let casa = GenderedNoun::new("casa", Gender::Feminine);
+
+        assert_eq!(pluralize_gendered(&casa, 0_u64, "es").unwrap(), "las casas");
+
+        set_singular_predicate(|n| n == 0 || n == 1);
+        assert_eq!(pluralize_gendered(&casa, 0, "es").unwrap(), "la casa");
+        assert_eq!(pluralize_gendered(&casa, -1, "es").unwrap(), "las casas");
+        assert_eq!(
+            pluralize_gendered(&GenderedNoun::new("house", Gender::Neuter), 0, "en").unwrap(),
+            "the house"
+        );
+
+        restore(base);
+    }
+
+    #[test]
+    fn grouped_counts_are_isolated() {
+        let _shared = lock_shared_rules();
+        let base = snapshot();
+
+        set_bidi_isolation(true);
+        assert_eq!(
+            pluralize_grouped("house", 1000000, true),
+            "\u{2066}1,000,000\u{2069} houses"
+        );
+        assert_eq!(
+            pluralize_grouped_by("house", -1, true, '.'),
+            "\u{2066}-1\u{2069} house"
+        );
+        assert_eq!(pluralize_grouped("house", 1000, false), "houses");
+
+        restore(base);
+        assert_eq!(pluralize_grouped("house", 1000, true), "1,000 houses");
+    }
+
+    #[test]
+    fn keeps_singular_possessives_with_a_bare_apostrophe() {
+        assert_eq!(singular("James'"), "James'");
+        assert_eq!(singular("Achilles'"), "Achilles'");
+        assert_eq!(singular("boss'"), "boss'");
+        assert_eq!(singular("bus’"), "bus’");
+        assert_eq!(singular("cats'"), "cat's");
+        assert_eq!(singular("CATS'"), "CAT'S");
+        assert_eq!(plural("boss'"), "bosses'");
+    }
 }
@@ -0,0 +1,66 @@
+//! Bindings exporting a `Pluralizer` class to JavaScript through `wasm-bindgen`.
+//!
+//! ```js
+//! import { Pluralizer } from "pluralizer";
+//!
+//! const pluralizer = new Pluralizer();
+//!
+//! pluralizer.pluralize("house", 2, true); // 2 houses
+//! pluralizer.singular("geese"); // goose
+//! ```
+
+use alloc::string::String;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Set of rules exported to JavaScript as the `Pluralizer` class.
+///
+/// Wraps a [Pluralizer](crate::Pluralizer) seeded with the default rules, owned by the JavaScript
+/// object instead of the global rules.
+#[wasm_bindgen(js_name = Pluralizer)]
+#[derive(Debug, Clone, Default)]
+pub struct WasmPluralizer(crate::Pluralizer);
+
+#[wasm_bindgen(js_class = Pluralizer)]
+impl WasmPluralizer {
+    /// Create a new instance seeded with the default rules.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self(crate::Pluralizer::new())
+    }
+
+    /// Pluralize or singularize a word based on the passed in count.
+    ///
+    /// See [pluralize](fn@crate::pluralize).
+    pub fn pluralize(&self, word: &str, count: isize, include_count: bool) -> String {
+        self.0.pluralize(word, count, include_count)
+    }
+
+    /// Convert a word to its plural form.
+    ///
+    /// See [plural](crate::plural).
+    pub fn plural(&self, word: &str) -> String {
+        self.0.plural(word)
+    }
+
+    /// Convert a word to its singular form.
+    ///
+    /// See [singular](crate::singular).
+    pub fn singular(&self, word: &str) -> String {
+        self.0.singular(word)
+    }
+
+    /// Add an irregular word definition.
+    ///
+    /// See [add_irregular_rule](crate::add_irregular_rule).
+    #[wasm_bindgen(js_name = addIrregularRule)]
+    pub fn add_irregular_rule(&mut self, singular: String, plural: String) {
+        self.0.add_irregular_rule(singular, plural)
+    }
+}
+
+impl From<crate::Pluralizer> for WasmPluralizer {
+    fn from(pluralizer: crate::Pluralizer) -> Self {
+        Self(pluralizer)
+    }
+}
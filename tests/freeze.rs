@@ -0,0 +1,72 @@
+// Freezing the rules lasts for the rest of the process, so it's tested in its own binary.
+#![cfg(not(feature = "thread-local"))]
+
+use pluralizer::{Priority, RuleConfig, RuleError};
+use regex::Regex;
+
+#[test]
+fn frozen_rules_return_errors() {
+    let scope = pluralizer::scoped();
+    scope.add_irregular_rule("zlorp".to_string(), "zlorpen".to_string());
+
+    pluralizer::freeze();
+
+    // Dropping the scope keeps its rules instead of panicking.
+    drop(scope);
+    assert_eq!(pluralizer::plural("zlorp"), "zlorpen");
+
+    let rule = || Regex::new("(?i)(blorp)$").unwrap();
+    let placement = || "$1en".to_string();
+
+    assert_eq!(
+        pluralizer::add_plural_rule(rule(), placement()),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::add_plural_rule_with_priority(rule(), placement(), Priority::First),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::try_add_plural_rule("(?i)(blorp)$", placement()),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::add_plural_rule_checked(rule(), placement()),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::add_singular_rule(rule(), placement()),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::add_singular_rule_with_priority(rule(), placement(), Priority::Last),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::try_add_singular_rule("(?i)(blorp)$", placement()),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::add_singular_rule_checked(rule(), placement()),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::try_add_uncountable_rule("(?i)blorp$"),
+        Err(RuleError::Frozen)
+    );
+    assert_eq!(
+        pluralizer::load_config(&RuleConfig::default()),
+        Err(RuleError::Frozen)
+    );
+
+    #[cfg(feature = "json")]
+    {
+        let path = std::env::temp_dir().join("pluralizer-frozen-rules.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        let result = pluralizer::load_rules_from_json(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(pluralizer::LoadError::Frozen)));
+    }
+}